@@ -0,0 +1,172 @@
+use std::time::Duration;
+
+use tokio::time::Instant;
+
+use crate::alert::{AlertEvent, AlertKind};
+use crate::config::ALERT_BATCH_WINDOW_SECS;
+
+/// Buffers alerts bound for notification sinks so many crossings become one
+/// summary per window instead of one message each. A zero window passes
+/// every event straight through.
+pub struct AlertBatcher {
+    window: Duration,
+    pending: Vec<AlertEvent>,
+    opened_at: Option<Instant>,
+}
+
+impl AlertBatcher {
+    pub fn new(window: Duration) -> Self {
+        Self {
+            window,
+            pending: Vec::new(),
+            opened_at: None,
+        }
+    }
+
+    pub fn from_config() -> Self {
+        Self::new(Duration::from_secs(ALERT_BATCH_WINDOW_SECS))
+    }
+
+    /// Returns the events to notify about one by one, and a summary of the
+    /// batch once its window has passed. The window opens with the first
+    /// event after a flush. Call it regularly, even without new events, so
+    /// batches are flushed on time.
+    pub fn poll(
+        &mut self,
+        events: Vec<AlertEvent>,
+        now: Instant,
+    ) -> (Vec<AlertEvent>, Option<String>) {
+        if self.window.is_zero() {
+            return (events, None);
+        }
+
+        if !events.is_empty() && self.opened_at.is_none() {
+            self.opened_at = Some(now);
+        }
+        self.pending.extend(events);
+
+        match self.opened_at {
+            Some(opened_at) if now.duration_since(opened_at) >= self.window => {
+                self.opened_at = None;
+                let batch = std::mem::take(&mut self.pending);
+                (Vec::new(), Some(Self::summarize(&batch)))
+            }
+            _ => (Vec::new(), None),
+        }
+    }
+
+    /// Closes the open batch early, returning its summary if it holds any
+    /// events, so nothing is lost on shutdown.
+    pub fn flush(&mut self) -> Option<String> {
+        self.opened_at = None;
+        let batch = std::mem::take(&mut self.pending);
+        (!batch.is_empty()).then(|| Self::summarize(&batch))
+    }
+
+    /// One clause per alert kind, e.g. `5 coins crossed threshold: BTC, ETH,
+    /// SOL, ARB, OP`.
+    fn summarize(batch: &[AlertEvent]) -> String {
+        let clauses: Vec<String> = [
+            AlertKind::ThresholdCrossing,
+            AlertKind::SignFlip,
+            AlertKind::ArbSpread,
+        ]
+        .into_iter()
+        .filter_map(|kind| {
+            let mut coins: Vec<&str> = batch
+                .iter()
+                .filter(|e| e.kind == kind)
+                .map(|e| e.coin.as_str())
+                .collect();
+            coins.sort_unstable();
+            coins.dedup();
+            if coins.is_empty() {
+                return None;
+            }
+            let what = match kind {
+                AlertKind::ThresholdCrossing => "crossed threshold",
+                AlertKind::SignFlip => "flipped sign",
+                AlertKind::ArbSpread => "crossed arb spread",
+            };
+            let noun = if coins.len() == 1 { "coin" } else { "coins" };
+            Some(format!(
+                "{} {} {}: {}",
+                coins.len(),
+                noun,
+                what,
+                coins.join(", ")
+            ))
+        })
+        .collect();
+        clauses.join("; ")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn event(coin: &str, kind: AlertKind) -> AlertEvent {
+        AlertEvent::new(coin, 1, kind, 0.0001)
+    }
+
+    #[test]
+    fn window_collects_events_into_one_summary() {
+        let mut batcher = AlertBatcher::new(Duration::from_secs(60));
+        let start = Instant::now();
+
+        let (immediate, summary) = batcher.poll(
+            vec![
+                event("ETH", AlertKind::ThresholdCrossing),
+                event("BTC", AlertKind::ThresholdCrossing),
+            ],
+            start,
+        );
+        assert!(immediate.is_empty());
+        assert_eq!(summary, None);
+
+        let (_, summary) = batcher.poll(
+            vec![event("SOL", AlertKind::SignFlip)],
+            start + Duration::from_secs(30),
+        );
+        assert_eq!(summary, None);
+
+        let (immediate, summary) = batcher.poll(Vec::new(), start + Duration::from_secs(60));
+        assert!(immediate.is_empty());
+        assert_eq!(
+            summary.as_deref(),
+            Some("2 coins crossed threshold: BTC, ETH; 1 coin flipped sign: SOL")
+        );
+
+        // The next window only opens with the next event
+        let later = start + Duration::from_secs(600);
+        let (immediate, summary) = batcher.poll(Vec::new(), later);
+        assert!(immediate.is_empty());
+        assert_eq!(summary, None);
+    }
+
+    #[test]
+    fn zero_window_passes_events_through() {
+        let mut batcher = AlertBatcher::new(Duration::ZERO);
+        let (immediate, summary) =
+            batcher.poll(vec![event("BTC", AlertKind::SignFlip)], Instant::now());
+        assert_eq!(immediate.len(), 1);
+        assert_eq!(summary, None);
+        assert_eq!(batcher.flush(), None);
+    }
+
+    #[test]
+    fn flush_closes_the_open_batch() {
+        let mut batcher = AlertBatcher::new(Duration::from_secs(60));
+        let start = Instant::now();
+        batcher.poll(vec![event("BTC", AlertKind::ArbSpread)], start);
+
+        assert_eq!(
+            batcher.flush().as_deref(),
+            Some("1 coin crossed arb spread: BTC")
+        );
+        assert_eq!(batcher.flush(), None);
+        let (_, summary) = batcher.poll(Vec::new(), start + Duration::from_secs(60));
+        assert_eq!(summary, None);
+    }
+}
@@ -0,0 +1,106 @@
+use std::collections::HashMap;
+use std::time::Duration;
+
+use tokio::time::Instant;
+
+use crate::alert::{AlertEvent, AlertKind};
+use crate::config::ALERT_COOLDOWN_SECS;
+
+/// Keeps a coin from notifying about the same kind of alert more than once
+/// per cooldown, so a rate hovering at the threshold doesn't send a message
+/// on every crossing. Only the sinks are throttled; history keeps every
+/// event. A zero cooldown lets everything through.
+pub struct AlertCooldown {
+    period: Duration,
+    last_sent: HashMap<(String, AlertKind), Instant>,
+}
+
+impl AlertCooldown {
+    pub fn new(period: Duration) -> Self {
+        Self {
+            period,
+            last_sent: HashMap::new(),
+        }
+    }
+
+    pub fn from_config() -> Self {
+        Self::new(Duration::from_secs(ALERT_COOLDOWN_SECS))
+    }
+
+    /// Returns the events whose coin and kind are out of cooldown, starting
+    /// a new cooldown for each.
+    pub fn admit(&mut self, events: &[AlertEvent], now: Instant) -> Vec<AlertEvent> {
+        if self.period.is_zero() {
+            return events.to_vec();
+        }
+        let period = self.period;
+        self.last_sent
+            .retain(|_, sent| now.duration_since(*sent) < period);
+        events
+            .iter()
+            .filter(|event| {
+                let key = (event.coin.clone(), event.kind);
+                if self.last_sent.contains_key(&key) {
+                    return false;
+                }
+                self.last_sent.insert(key, now);
+                true
+            })
+            .cloned()
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn event(coin: &str, kind: AlertKind) -> AlertEvent {
+        AlertEvent::new(coin, 1, kind, 0.0001)
+    }
+
+    #[test]
+    fn repeats_wait_out_the_cooldown() {
+        let mut cooldown = AlertCooldown::new(Duration::from_secs(60));
+        let start = Instant::now();
+        let crossing = event("BTC", AlertKind::ThresholdCrossing);
+
+        assert_eq!(
+            cooldown.admit(std::slice::from_ref(&crossing), start).len(),
+            1
+        );
+        assert!(
+            cooldown
+                .admit(
+                    std::slice::from_ref(&crossing),
+                    start + Duration::from_secs(59)
+                )
+                .is_empty()
+        );
+        // Other coins and kinds have their own cooldown
+        let others = [
+            event("ETH", AlertKind::ThresholdCrossing),
+            event("BTC", AlertKind::SignFlip),
+        ];
+        assert_eq!(
+            cooldown
+                .admit(&others, start + Duration::from_secs(59))
+                .len(),
+            2
+        );
+        assert_eq!(
+            cooldown
+                .admit(&[crossing], start + Duration::from_secs(60))
+                .len(),
+            1
+        );
+    }
+
+    #[test]
+    fn zero_cooldown_admits_everything() {
+        let mut cooldown = AlertCooldown::new(Duration::ZERO);
+        let crossing = event("BTC", AlertKind::ThresholdCrossing);
+        let events = [crossing.clone(), crossing];
+        assert_eq!(cooldown.admit(&events, Instant::now()).len(), 2);
+    }
+}
@@ -0,0 +1,92 @@
+use serde_json::json;
+
+use crate::alert::{
+    AlertEvent, notify_desktop, notify_desktop_text, post_telegram, post_telegram_text,
+    post_webhook, post_webhook_json,
+};
+use crate::config::{
+    ALERT_WEBHOOK_URL, DESKTOP_NOTIFICATIONS, TELEGRAM_BOT_TOKEN, TELEGRAM_CHAT_ID,
+};
+use crate::paths::write_debug_log;
+
+fn log_debug(msg: String) {
+    write_debug_log("ALERT", &msg);
+}
+
+/// Sends an alert to every configured sink in the background. Each
+/// sink is one more consumer of the same event; failures are logged and
+/// never interrupt the alerter.
+pub fn deliver(event: &AlertEvent) {
+    if let Some(url) = ALERT_WEBHOOK_URL {
+        let event = event.clone();
+        tokio::spawn(async move {
+            if let Err(e) = post_webhook(url, &event).await {
+                log_debug(format!("Webhook for {} failed: {}", event.coin, e));
+            }
+        });
+    }
+    if let (Some(token), Some(chat_id)) = (TELEGRAM_BOT_TOKEN, TELEGRAM_CHAT_ID) {
+        let event = event.clone();
+        tokio::spawn(async move {
+            // reqwest errors can echo the URL, which carries the bot token
+            if let Err(e) = post_telegram(token, chat_id, &event).await {
+                log_debug(format!(
+                    "Telegram for {} failed: {}",
+                    event.coin,
+                    e.without_url()
+                ));
+            }
+        });
+    }
+    if DESKTOP_NOTIFICATIONS {
+        let event = event.clone();
+        tokio::spawn(async move {
+            if let Err(e) = notify_desktop(&event).await {
+                log_debug(format!(
+                    "Desktop notification for {} failed: {}",
+                    event.coin, e
+                ));
+            }
+        });
+    }
+}
+
+/// Sends a batch summary from `AlertBatcher` to every configured sink in
+/// the background, the same way `deliver` sends single alerts.
+pub fn deliver_summary(summary: &str) {
+    tokio::spawn(send_summary(summary.to_string()));
+}
+
+/// Sends a summary to every configured sink and waits until each has
+/// finished or failed.
+pub async fn send_summary(summary: String) {
+    let webhook = async {
+        if let Some(url) = ALERT_WEBHOOK_URL {
+            let body = json!({ "event": "alert_summary", "summary": summary });
+            if let Err(e) = post_webhook_json(url, &body).await {
+                log_debug(format!("Webhook for alert summary failed: {}", e));
+            }
+        }
+    };
+    let telegram = async {
+        if let (Some(token), Some(chat_id)) = (TELEGRAM_BOT_TOKEN, TELEGRAM_CHAT_ID)
+            && let Err(e) = post_telegram_text(token, chat_id, &summary).await
+        {
+            log_debug(format!(
+                "Telegram for alert summary failed: {}",
+                e.without_url()
+            ));
+        }
+    };
+    let desktop = async {
+        if DESKTOP_NOTIFICATIONS
+            && let Err(e) = notify_desktop_text("Funding alerts", &summary).await
+        {
+            log_debug(format!(
+                "Desktop notification for alert summary failed: {}",
+                e
+            ));
+        }
+    };
+    tokio::join!(webhook, telegram, desktop);
+}
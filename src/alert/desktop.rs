@@ -0,0 +1,39 @@
+use std::io;
+
+use tokio::process::Command;
+
+use crate::alert::AlertEvent;
+
+/// Shows an alert as a desktop notification.
+pub async fn notify_desktop(event: &AlertEvent) -> io::Result<()> {
+    notify_desktop_text("Funding alert", &event.message()).await
+}
+
+/// Shows a desktop notification through the platform's own tool:
+/// `osascript` on macOS, `notify-send` everywhere else.
+pub async fn notify_desktop_text(title: &str, text: &str) -> io::Result<()> {
+    let mut command = if cfg!(target_os = "macos") {
+        // Passed as arguments so quotes in the text can't break the script
+        let mut command = Command::new("osascript");
+        command.args([
+            "-e",
+            "on run argv",
+            "-e",
+            "display notification (item 2 of argv) with title (item 1 of argv)",
+            "-e",
+            "end run",
+            title,
+            text,
+        ]);
+        command
+    } else {
+        let mut command = Command::new("notify-send");
+        command.args([title, text]);
+        command
+    };
+    let status = command.kill_on_drop(true).status().await?;
+    if !status.success() {
+        return Err(io::Error::other(format!("notifier exited with {}", status)));
+    }
+    Ok(())
+}
@@ -0,0 +1,82 @@
+use std::time::Duration;
+
+use chrono::Utc;
+use tokio::time::Instant;
+
+use crate::alert::{
+    AlertBatcher, AlertCooldown, AlertEvent, QuietHours, deliver, deliver_summary, send_summary,
+};
+use crate::config::SHUTDOWN_TIMEOUT_MS;
+use crate::paths::write_debug_log;
+
+fn log_debug(msg: String) {
+    write_debug_log("ALERT", &msg);
+}
+
+/// The path from detected alerts to the notification sinks, shared by the
+/// UI and the headless watcher: quiet hours, then the per-coin cooldown,
+/// then batching, then every configured sink.
+pub struct AlertDispatch {
+    quiet_hours: QuietHours,
+    cooldown: AlertCooldown,
+    batcher: AlertBatcher,
+}
+
+impl AlertDispatch {
+    pub fn from_config() -> Self {
+        Self {
+            quiet_hours: QuietHours::from_config(),
+            cooldown: AlertCooldown::from_config(),
+            batcher: AlertBatcher::from_config(),
+        }
+    }
+
+    /// Sends what is due to the sinks and returns the events to record:
+    /// everything quiet hours let through, plus the alerts held over a
+    /// window that just ended. Held alerts reach the sinks as one summary,
+    /// which is also returned. Call it regularly, even without new events,
+    /// so held alerts and batches go out on time.
+    pub fn dispatch(&mut self, events: Vec<AlertEvent>) -> (Vec<AlertEvent>, Option<String>) {
+        let (events, release) = self.quiet_hours.gate(events, Utc::now());
+        let now = Instant::now();
+        let notify = self.cooldown.admit(&events, now);
+        let (immediate, batch_summary) = self.batcher.poll(notify, now);
+        for event in &immediate {
+            deliver(event);
+        }
+        if let Some(batch_summary) = batch_summary {
+            deliver_summary(&batch_summary);
+        }
+
+        let Some(release) = release else {
+            return (events, None);
+        };
+        deliver_summary(&release.summary);
+        let mut record = release.events;
+        record.extend(events);
+        (record, Some(release.summary))
+    }
+
+    /// Whether alerts are currently held back by quiet hours.
+    pub fn is_quiet(&self) -> bool {
+        self.quiet_hours.is_quiet(Utc::now())
+    }
+
+    /// Sends the batch still open, if any, and waits for the sinks for up to
+    /// `SHUTDOWN_TIMEOUT_MS`. Call it once on shutdown.
+    pub async fn flush(&mut self) {
+        let Some(summary) = self.batcher.flush() else {
+            return;
+        };
+        let timeout = Duration::from_millis(SHUTDOWN_TIMEOUT_MS);
+        if tokio::time::timeout(timeout, send_summary(summary))
+            .await
+            .is_err()
+        {
+            log_debug(format!(
+                "Alert sinks still busy after {}ms, dropping the last batch",
+                SHUTDOWN_TIMEOUT_MS
+            ));
+        }
+    }
+}
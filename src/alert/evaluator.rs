@@ -0,0 +1,94 @@
+use std::collections::HashSet;
+
+use crate::alert::{AlertEvent, AlertKind};
+use crate::config::{ARB_ALERT_SPREAD, FUNDING_RATE_THRESHOLD, THRESHOLD_STATE_FILE};
+use crate::data::{CoinData, CoinUpdate};
+use crate::paths::load_state;
+
+/// The alert threshold to start with: the value last set in the app, or the
+/// configured default.
+pub fn initial_threshold() -> f64 {
+    load_state(THRESHOLD_STATE_FILE)
+        .and_then(|s| s.parse().ok())
+        .unwrap_or(FUNDING_RATE_THRESHOLD)
+}
+
+/// Applies an update to a coin and returns the alerts the change triggers.
+///
+/// The first update only seeds the coin, and suspect funding values are
+/// neither alerted on nor used as a baseline. Muted coins are updated but
+/// never alert. Once two venues quote the coin, the gap between them
+/// crossing `ARB_ALERT_SPREAD` raises an arb alert.
+pub fn apply_update(
+    coin: &mut CoinData,
+    update: &CoinUpdate,
+    threshold: f64,
+    muted: &HashSet<String>,
+) -> Vec<AlertEvent> {
+    let had_data = coin.has_data();
+    let was_suspect = coin.is_funding_suspect();
+    let previous = coin.alert_funding();
+    let previous_spread = coin.funding_spread();
+    coin.apply(update);
+
+    if !had_data || muted.contains(&coin.coin) {
+        return Vec::new();
+    }
+    let mut events = Vec::new();
+    if !was_suspect && !coin.is_funding_suspect() {
+        events = AlertEvent::detect(
+            &update.coin,
+            update.exchange,
+            previous,
+            coin.alert_funding(),
+            threshold,
+        );
+    }
+    if let (Some(limit), Some(previous), Some(current)) =
+        (ARB_ALERT_SPREAD, previous_spread, coin.funding_spread())
+        && (previous >= limit) != (current >= limit)
+    {
+        events.push(AlertEvent::new(
+            &update.coin,
+            update.exchange,
+            AlertKind::ArbSpread,
+            current,
+        ));
+    }
+    events
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::data::{HYPERLIQUID, LIGHTER};
+
+    fn combined(funding: f64, venue: u8) -> CoinUpdate {
+        CoinUpdate::new("BTC".to_string(), funding, 10.0, 50_000.0, 3, venue)
+    }
+
+    #[test]
+    fn arb_alert_fires_when_the_spread_crosses() {
+        let Some(limit) = ARB_ALERT_SPREAD else {
+            return;
+        };
+        let muted = HashSet::new();
+        let mut coin = CoinData::new("BTC".to_string());
+        assert!(apply_update(&mut coin, &combined(0.0, HYPERLIQUID), 1.0, &muted).is_empty());
+        assert!(apply_update(&mut coin, &combined(0.0, LIGHTER), 1.0, &muted).is_empty());
+
+        // Hyperliquid quotes an hourly fraction, so this opens the spread
+        let events = apply_update(&mut coin, &combined(limit * 2.0, HYPERLIQUID), 1.0, &muted);
+        assert_eq!(events.len(), 1);
+        assert_eq!(events[0].kind, AlertKind::ArbSpread);
+        assert!((events[0].value - limit * 2.0).abs() < 1e-15);
+
+        // Still open: no repeat
+        let events = apply_update(&mut coin, &combined(limit * 3.0, HYPERLIQUID), 1.0, &muted);
+        assert!(events.is_empty());
+
+        let events = apply_update(&mut coin, &combined(0.0, HYPERLIQUID), 1.0, &muted);
+        assert_eq!(events.len(), 1);
+        assert_eq!(events[0].kind, AlertKind::ArbSpread);
+    }
+}
@@ -0,0 +1,120 @@
+use chrono::{DateTime, Utc};
+use serde_json::json;
+
+use crate::data::{FundingRateRound, precision_for};
+use crate::time::Timezone;
+
+/// Whether an hourly funding rate is currently past the alert threshold.
+pub fn is_alerting(funding: f64, threshold: f64) -> bool {
+    funding > threshold
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum AlertKind {
+    ThresholdCrossing,
+    SignFlip,
+    /// The funding gap between venues crossed `ARB_ALERT_SPREAD`; the event
+    /// value is the spread rather than a venue's rate.
+    ArbSpread,
+}
+
+impl AlertKind {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            AlertKind::ThresholdCrossing => "threshold_crossing",
+            AlertKind::SignFlip => "sign_flip",
+            AlertKind::ArbSpread => "arb_spread",
+        }
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct AlertEvent {
+    pub timestamp: DateTime<Utc>,
+    pub coin: String,
+    pub exchange: u8,
+    pub kind: AlertKind,
+    pub value: f64,
+}
+
+impl AlertEvent {
+    pub fn new(coin: &str, exchange: u8, kind: AlertKind, value: f64) -> Self {
+        Self {
+            timestamp: Utc::now(),
+            coin: coin.to_string(),
+            exchange,
+            kind,
+            value,
+        }
+    }
+
+    /// Compares a coin's previous and new hourly funding and returns the
+    /// alerts triggered by the change.
+    pub fn detect(
+        coin: &str,
+        exchange: u8,
+        previous: f64,
+        current: f64,
+        threshold: f64,
+    ) -> Vec<AlertEvent> {
+        let mut events = Vec::new();
+
+        if is_alerting(previous, threshold) != is_alerting(current, threshold) {
+            events.push(Self::new(
+                coin,
+                exchange,
+                AlertKind::ThresholdCrossing,
+                current,
+            ));
+        }
+
+        if previous != 0.0 && current != 0.0 && (previous < 0.0) != (current < 0.0) {
+            events.push(Self::new(coin, exchange, AlertKind::SignFlip, current));
+        }
+
+        events
+    }
+
+    pub fn exchange_name(&self) -> &'static str {
+        match self.exchange {
+            1 => "HL",
+            2 => "LT",
+            3 => "BOTH",
+            _ => "?",
+        }
+    }
+
+    /// One-line human readable description, e.g.
+    /// `BTC on HL: threshold crossing at 0.001300%/h`.
+    pub fn message(&self) -> String {
+        format!(
+            "{} on {}: {} at {:.*}%/h",
+            self.coin,
+            self.exchange_name(),
+            self.kind.as_str().replace('_', " "),
+            precision_for(FundingRateRound::Hourly),
+            self.value * 100.0
+        )
+    }
+
+    /// Fields of a CSV row, in `timestamp,coin,exchange,event,value` order.
+    pub fn csv_record(&self) -> [String; 5] {
+        [
+            Timezone::display().format(self.timestamp, "%+"),
+            self.coin.clone(),
+            self.exchange_name().to_string(),
+            self.kind.as_str().to_string(),
+            self.value.to_string(),
+        ]
+    }
+
+    pub fn to_json(&self) -> serde_json::Value {
+        json!({
+            "timestamp": Timezone::display().format(self.timestamp, "%+"),
+            "coin": self.coin,
+            "exchange": self.exchange_name(),
+            "event": self.kind.as_str(),
+            "value": self.value,
+        })
+    }
+}
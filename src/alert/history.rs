@@ -0,0 +1,151 @@
+use color_eyre::Result;
+use std::collections::VecDeque;
+use std::fs::{self, OpenOptions};
+use std::path::{Path, PathBuf};
+use std::sync::mpsc;
+
+use crate::alert::AlertEvent;
+use crate::config::{ALERT_LOG_FILE, ALERT_LOG_MAX_BYTES};
+use crate::paths::data_file;
+use crate::time::Timezone;
+
+const CSV_HEADER: [&str; 5] = ["timestamp", "coin", "exchange", "event", "value"];
+
+/// Bounded in-memory log of fired alerts, oldest first.
+pub struct AlertHistory {
+    events: VecDeque<AlertEvent>,
+    capacity: usize,
+    /// Feeds the rolling log writer when `ALERT_LOG_FILE` is set.
+    rolling_log: Option<mpsc::Sender<AlertEvent>>,
+}
+
+impl AlertHistory {
+    pub fn new(capacity: usize) -> Self {
+        Self {
+            events: VecDeque::with_capacity(capacity),
+            capacity,
+            rolling_log: ALERT_LOG_FILE.map(|name| Self::spawn_rolling_log(data_file(name))),
+        }
+    }
+
+    pub fn push(&mut self, event: AlertEvent) {
+        if let Some(log) = &self.rolling_log {
+            let _ = log.send(event.clone());
+        }
+
+        if self.capacity == 0 {
+            return;
+        }
+        if self.events.len() == self.capacity {
+            self.events.pop_front();
+        }
+        self.events.push_back(event);
+    }
+
+    pub fn len(&self) -> usize {
+        self.events.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.events.is_empty()
+    }
+
+    /// The history as CSV. Fields are quoted where needed, so symbols with
+    /// commas or quotes stay in their column.
+    pub fn to_csv(&self) -> Result<String> {
+        let mut writer = csv::Writer::from_writer(Vec::new());
+        writer.write_record(CSV_HEADER)?;
+        for event in &self.events {
+            writer.write_record(event.csv_record())?;
+        }
+        Ok(String::from_utf8(writer.into_inner()?)?)
+    }
+
+    pub fn to_json(&self) -> Result<String> {
+        let events: Vec<_> = self.events.iter().map(AlertEvent::to_json).collect();
+        Ok(serde_json::to_string_pretty(&events)?)
+    }
+
+    /// Writes the history to a timestamped file in the data directory and
+    /// returns its path. `json` selects JSON over CSV.
+    pub fn export(&self, json: bool) -> Result<PathBuf> {
+        let extension = if json { "json" } else { "csv" };
+        let path = data_file(&format!(
+            "hype_alerts_{}.{}",
+            Timezone::display().format_now("%Y%m%d_%H%M%S"),
+            extension
+        ));
+        let contents = if json {
+            self.to_json()?
+        } else {
+            self.to_csv()?
+        };
+        fs::write(&path, contents)?;
+        Ok(path)
+    }
+
+    /// Appends events to the rolling log on a dedicated thread, so a slow
+    /// disk never holds up the caller. The thread exits once the history
+    /// is dropped.
+    fn spawn_rolling_log(path: PathBuf) -> mpsc::Sender<AlertEvent> {
+        let (tx, rx) = mpsc::channel::<AlertEvent>();
+        std::thread::spawn(move || {
+            for event in rx {
+                let _ = Self::append_rolling(&path, &event);
+            }
+        });
+        tx
+    }
+
+    /// Appends an event to the rolling CSV log, moving the current file to
+    /// `<path>.1` once it grows past `ALERT_LOG_MAX_BYTES`.
+    fn append_rolling(path: &Path, event: &AlertEvent) -> Result<()> {
+        let size = fs::metadata(path).map(|m| m.len()).unwrap_or(0);
+        if size > ALERT_LOG_MAX_BYTES {
+            let mut rotated = path.as_os_str().to_owned();
+            rotated.push(".1");
+            fs::rename(path, rotated)?;
+        }
+
+        let is_new = !path.exists();
+        let file = OpenOptions::new().create(true).append(true).open(path)?;
+        let mut writer = csv::Writer::from_writer(file);
+        if is_new {
+            writer.write_record(CSV_HEADER)?;
+        }
+        writer.write_record(event.csv_record())?;
+        writer.flush()?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::alert::AlertKind;
+
+    #[test]
+    fn csv_quotes_unusual_symbols_and_reads_back() {
+        let symbols = ["FOO,BAR", "BTC/USD", "1000PEPE", "QU\"OTE", "kPEPE"];
+        let mut history = AlertHistory {
+            events: VecDeque::new(),
+            capacity: symbols.len(),
+            rolling_log: None,
+        };
+        for symbol in symbols {
+            history.push(AlertEvent::new(symbol, 1, AlertKind::SignFlip, -0.0001));
+        }
+
+        let csv = history.to_csv().unwrap();
+        let mut reader = csv::Reader::from_reader(csv.as_bytes());
+        assert_eq!(reader.headers().unwrap(), CSV_HEADER.as_slice());
+        let rows: Vec<csv::StringRecord> = reader.records().map(Result::unwrap).collect();
+        assert_eq!(rows.len(), symbols.len());
+        for (row, symbol) in rows.iter().zip(symbols) {
+            assert_eq!(row.len(), CSV_HEADER.len());
+            assert_eq!(&row[1], symbol);
+            assert_eq!(&row[3], "sign_flip");
+            assert_eq!(row[4].parse::<f64>().unwrap(), -0.0001);
+        }
+    }
+}
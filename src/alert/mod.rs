@@ -0,0 +1,25 @@
+pub mod batch;
+pub mod cooldown;
+pub mod delivery;
+pub mod desktop;
+pub mod dispatch;
+pub mod evaluator;
+pub mod event;
+pub mod history;
+pub mod mute;
+pub mod quiet_hours;
+pub mod telegram;
+pub mod webhook;
+
+pub use batch::AlertBatcher;
+pub use cooldown::AlertCooldown;
+pub use delivery::{deliver, deliver_summary, send_summary};
+pub use desktop::{notify_desktop, notify_desktop_text};
+pub use dispatch::AlertDispatch;
+pub use evaluator::{apply_update, initial_threshold};
+pub use event::{AlertEvent, AlertKind, is_alerting};
+pub use history::AlertHistory;
+pub use mute::{load_muted, save_muted};
+pub use quiet_hours::{QuietHours, QuietRelease, QuietWindow};
+pub use telegram::{post_telegram, post_telegram_text};
+pub use webhook::{post_webhook, post_webhook_json};
@@ -0,0 +1,24 @@
+use std::collections::HashSet;
+use std::io;
+
+use crate::config::MUTED_STATE_FILE;
+use crate::paths::{load_state, save_state};
+
+/// Coins muted from alerting in the app, one per line in the state file.
+pub fn load_muted() -> HashSet<String> {
+    load_state(MUTED_STATE_FILE)
+        .map(|s| {
+            s.lines()
+                .map(str::trim)
+                .filter(|l| !l.is_empty())
+                .map(str::to_string)
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
+pub fn save_muted(muted: &HashSet<String>) -> io::Result<()> {
+    let mut coins: Vec<&str> = muted.iter().map(String::as_str).collect();
+    coins.sort_unstable();
+    save_state(MUTED_STATE_FILE, &coins.join("\n"))
+}
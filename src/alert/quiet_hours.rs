@@ -0,0 +1,243 @@
+use std::collections::{BTreeSet, VecDeque};
+
+use chrono::{DateTime, NaiveTime, Utc};
+
+use crate::alert::AlertEvent;
+use crate::config::{ALERT_HISTORY_CAPACITY, ALERT_QUIET_HOURS, QUEUE_QUIET_ALERTS};
+use crate::paths::write_debug_log;
+
+fn log_debug(msg: String) {
+    write_debug_log("ALERT", &msg);
+}
+
+/// A daily UTC window, which wraps midnight when `end` is before `start`
+/// and covers the whole day when they are equal.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct QuietWindow {
+    start: NaiveTime,
+    end: NaiveTime,
+}
+
+impl QuietWindow {
+    /// Parses `HH:MM-HH:MM`.
+    pub fn parse(s: &str) -> Option<Self> {
+        let (start, end) = s.split_once('-')?;
+        Some(Self {
+            start: NaiveTime::parse_from_str(start.trim(), "%H:%M").ok()?,
+            end: NaiveTime::parse_from_str(end.trim(), "%H:%M").ok()?,
+        })
+    }
+
+    pub fn contains(&self, at: DateTime<Utc>) -> bool {
+        let time = at.time();
+        if self.start == self.end {
+            true
+        } else if self.start < self.end {
+            self.start <= time && time < self.end
+        } else {
+            time >= self.start || time < self.end
+        }
+    }
+}
+
+/// Alerts held over a quiet window that just ended.
+pub struct QuietRelease {
+    /// The most recent held alerts, at most `ALERT_HISTORY_CAPACITY`.
+    pub events: Vec<AlertEvent>,
+    pub summary: String,
+}
+
+/// Holds alerts back during the configured quiet hours, releasing them with
+/// a summary once the window ends when queueing is enabled. Only the most
+/// recent `capacity` alerts are kept, but the summary counts all of them.
+pub struct QuietHours {
+    windows: Vec<QuietWindow>,
+    queue: bool,
+    capacity: usize,
+    held: VecDeque<AlertEvent>,
+    held_count: usize,
+    held_coins: BTreeSet<String>,
+}
+
+impl QuietHours {
+    pub fn new(windows: Vec<QuietWindow>, queue: bool, capacity: usize) -> Self {
+        Self {
+            windows,
+            queue,
+            capacity,
+            held: VecDeque::new(),
+            held_count: 0,
+            held_coins: BTreeSet::new(),
+        }
+    }
+
+    /// Builds the gate from `ALERT_QUIET_HOURS`, skipping malformed windows.
+    /// Held alerts are capped at `ALERT_HISTORY_CAPACITY`, as many as the
+    /// history they are released into can keep.
+    pub fn from_config() -> Self {
+        let windows = ALERT_QUIET_HOURS
+            .iter()
+            .filter_map(|s| {
+                let window = QuietWindow::parse(s);
+                if window.is_none() {
+                    log_debug(format!("Ignoring malformed quiet hours {:?}", s));
+                }
+                window
+            })
+            .collect();
+        Self::new(windows, QUEUE_QUIET_ALERTS, ALERT_HISTORY_CAPACITY)
+    }
+
+    pub fn is_quiet(&self, at: DateTime<Utc>) -> bool {
+        self.windows.iter().any(|w| w.contains(at))
+    }
+
+    /// Returns the alerts that may fire now. During quiet hours the events
+    /// are held (or dropped when queueing is off); the first call after the
+    /// window ends also returns the held alerts with a summary of them,
+    /// which is what gets notified instead of replaying each one. Call it
+    /// regularly, even without new events, so held alerts are released.
+    pub fn gate(
+        &mut self,
+        events: Vec<AlertEvent>,
+        now: DateTime<Utc>,
+    ) -> (Vec<AlertEvent>, Option<QuietRelease>) {
+        if self.is_quiet(now) {
+            if self.queue {
+                self.hold(events);
+            }
+            return (Vec::new(), None);
+        }
+        if self.held_count == 0 {
+            return (events, None);
+        }
+
+        let summary = self.summarize();
+        log_debug(summary.clone());
+        self.held_count = 0;
+        self.held_coins.clear();
+        let release = QuietRelease {
+            events: std::mem::take(&mut self.held).into(),
+            summary,
+        };
+        (events, Some(release))
+    }
+
+    fn hold(&mut self, events: Vec<AlertEvent>) {
+        for event in events {
+            self.held_count += 1;
+            self.held_coins.insert(event.coin.clone());
+            if self.held.len() >= self.capacity {
+                self.held.pop_front();
+            }
+            if self.capacity > 0 {
+                self.held.push_back(event);
+            }
+        }
+    }
+
+    fn summarize(&self) -> String {
+        let coins: Vec<&str> = self.held_coins.iter().map(String::as_str).collect();
+        let mut summary = format!(
+            "{} alerts held during quiet hours for {} coins: {}",
+            self.held_count,
+            coins.len(),
+            coins.join(", ")
+        );
+        if self.held.len() < self.held_count {
+            summary.push_str(&format!(" (latest {} kept)", self.held.len()));
+        }
+        summary
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::alert::AlertKind;
+
+    fn at(time: &str) -> DateTime<Utc> {
+        format!("2026-01-01T{}:00Z", time).parse().unwrap()
+    }
+
+    fn event(coin: &str) -> AlertEvent {
+        AlertEvent::new(coin, 1, AlertKind::ThresholdCrossing, 0.0001)
+    }
+
+    #[test]
+    fn parses_windows() {
+        let window = QuietWindow::parse(" 22:00 - 07:30 ").unwrap();
+        assert!(window.contains(at("23:00")));
+        for bad in [
+            "",
+            "22:00",
+            "22:00-",
+            "25:00-07:00",
+            "22-07",
+            "22:00-07:00-08:00",
+        ] {
+            assert_eq!(QuietWindow::parse(bad), None, "{:?}", bad);
+        }
+    }
+
+    #[test]
+    fn windows_can_wrap_midnight() {
+        let day = QuietWindow::parse("09:00-17:00").unwrap();
+        assert!(day.contains(at("09:00")));
+        assert!(!day.contains(at("17:00")));
+        assert!(!day.contains(at("03:00")));
+
+        let night = QuietWindow::parse("22:00-07:00").unwrap();
+        assert!(night.contains(at("22:00")));
+        assert!(night.contains(at("00:00")));
+        assert!(night.contains(at("06:59")));
+        assert!(!night.contains(at("07:00")));
+        assert!(!night.contains(at("12:00")));
+    }
+
+    #[test]
+    fn equal_start_and_end_covers_the_day() {
+        let window = QuietWindow::parse("00:00-00:00").unwrap();
+        for time in ["00:00", "12:00", "23:59"] {
+            assert!(window.contains(at(time)), "{}", time);
+        }
+    }
+
+    #[test]
+    fn gate_holds_then_releases_with_a_summary() {
+        let night = QuietWindow::parse("22:00-07:00").unwrap();
+        let mut quiet = QuietHours::new(vec![night], true, 2);
+
+        let (passed, release) = quiet.gate(vec![event("ETH"), event("BTC")], at("23:00"));
+        assert!(passed.is_empty());
+        assert!(release.is_none());
+        let (passed, release) = quiet.gate(vec![event("BTC")], at("03:00"));
+        assert!(passed.is_empty());
+        assert!(release.is_none());
+
+        let (passed, release) = quiet.gate(vec![event("SOL")], at("07:00"));
+        assert_eq!(passed.len(), 1);
+        let release = release.unwrap();
+        let kept: Vec<&str> = release.events.iter().map(|e| e.coin.as_str()).collect();
+        assert_eq!(kept, ["BTC", "BTC"]);
+        assert_eq!(
+            release.summary,
+            "3 alerts held during quiet hours for 2 coins: BTC, ETH (latest 2 kept)"
+        );
+
+        // Released once only
+        let (passed, release) = quiet.gate(Vec::new(), at("08:00"));
+        assert!(passed.is_empty());
+        assert!(release.is_none());
+    }
+
+    #[test]
+    fn gate_drops_quiet_alerts_without_queueing() {
+        let night = QuietWindow::parse("22:00-07:00").unwrap();
+        let mut quiet = QuietHours::new(vec![night], false, 10);
+        quiet.gate(vec![event("BTC")], at("23:00"));
+        let (passed, release) = quiet.gate(Vec::new(), at("08:00"));
+        assert!(passed.is_empty());
+        assert!(release.is_none());
+    }
+}
@@ -0,0 +1,35 @@
+use serde_json::json;
+
+use crate::alert::AlertEvent;
+
+/// Sends an alert as a message from a Telegram bot to a chat.
+pub async fn post_telegram(
+    bot_token: &str,
+    chat_id: &str,
+    event: &AlertEvent,
+) -> Result<(), reqwest::Error> {
+    post_telegram_text(bot_token, chat_id, &event.message()).await
+}
+
+/// Sends a plain text message from a Telegram bot to a chat.
+pub async fn post_telegram_text(
+    bot_token: &str,
+    chat_id: &str,
+    text: &str,
+) -> Result<(), reqwest::Error> {
+    let body = json!({
+        "chat_id": chat_id,
+        "text": text,
+    });
+    reqwest::Client::new()
+        .post(format!(
+            "https://api.telegram.org/bot{}/sendMessage",
+            bot_token
+        ))
+        .header(reqwest::header::CONTENT_TYPE, "application/json")
+        .body(body.to_string())
+        .send()
+        .await?
+        .error_for_status()?;
+    Ok(())
+}
@@ -0,0 +1,18 @@
+use crate::alert::AlertEvent;
+
+/// Posts an alert to a webhook as its JSON representation.
+pub async fn post_webhook(url: &str, event: &AlertEvent) -> Result<(), reqwest::Error> {
+    post_webhook_json(url, &event.to_json()).await
+}
+
+/// Posts any JSON body to a webhook.
+pub async fn post_webhook_json(url: &str, body: &serde_json::Value) -> Result<(), reqwest::Error> {
+    reqwest::Client::new()
+        .post(url)
+        .header(reqwest::header::CONTENT_TYPE, "application/json")
+        .body(body.to_string())
+        .send()
+        .await?
+        .error_for_status()?;
+    Ok(())
+}
@@ -0,0 +1,46 @@
+use std::time::Duration;
+
+use tokio::time::timeout;
+
+use crate::config::CHECK_TIMEOUT_SECS;
+use crate::exchange::registry;
+
+/// Connectivity self-test behind `--check`: for every venue, fetches the
+/// coin list and opens the stream, printing one pass/fail line per step.
+/// Returns whether every step passed.
+pub async fn run_check() -> bool {
+    let limit = Duration::from_secs(CHECK_TIMEOUT_SECS);
+    let mut passed = true;
+
+    for exchange in registry() {
+        let coin_list = match timeout(limit, exchange.coin_list()).await {
+            Ok(Ok(coins)) => Ok(format!("{} coins", coins.len())),
+            Ok(Err(e)) => Err(e.to_string()),
+            Err(_) => Err(format!("timed out after {:?}", limit)),
+        };
+        let stream = match exchange.probe_stream(limit).await {
+            Ok(()) => Ok("connected".to_string()),
+            Err(e) => Err(e.to_string()),
+        };
+
+        for (step, result) in [("coin list", coin_list), ("websocket", stream)] {
+            match result {
+                Ok(detail) => println!("PASS  {:<12} {:<10} {}", exchange.name(), step, detail),
+                Err(reason) => {
+                    passed = false;
+                    println!("FAIL  {:<12} {:<10} {}", exchange.name(), step, reason);
+                }
+            }
+        }
+    }
+
+    println!(
+        "{}",
+        if passed {
+            "All checks passed"
+        } else {
+            "Some checks failed"
+        }
+    );
+    passed
+}
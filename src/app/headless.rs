@@ -0,0 +1,103 @@
+use std::collections::HashMap;
+use std::io::{IsTerminal, Write};
+use std::time::Duration;
+
+use color_eyre::Result;
+use serde_json::json;
+
+use crate::alert::{AlertDispatch, AlertHistory, apply_update, initial_threshold, load_muted};
+use crate::config::{ALERT_HISTORY_CAPACITY, POLL_DURATION_MS};
+use crate::data::{CoinData, UpdateReceiver};
+use crate::paths::write_debug_log;
+
+fn log_debug(msg: String) {
+    write_debug_log("HEADLESS", &msg);
+}
+
+/// Whether stdout can host the full-screen UI: it must be a TTY and
+/// `TERM` must not be `dumb`.
+pub fn terminal_supported() -> bool {
+    std::io::stdout().is_terminal() && std::env::var("TERM").is_ok_and(|term| term != "dumb")
+}
+
+/// Resolves on Ctrl-C, or on SIGTERM where signals are available.
+async fn shutdown_signal() {
+    #[cfg(unix)]
+    {
+        use tokio::signal::unix::{SignalKind, signal};
+
+        if let Ok(mut terminate) = signal(SignalKind::terminate()) {
+            tokio::select! {
+                _ = tokio::signal::ctrl_c() => {}
+                _ = terminate.recv() => {}
+            }
+            return;
+        }
+    }
+    let _ = tokio::signal::ctrl_c().await;
+}
+
+/// Headless fallback for terminals that can't run the UI: writes every
+/// update to stdout as one JSON object per line until shut down.
+pub async fn stream_json(mut rx: UpdateReceiver) -> Result<()> {
+    let shutdown = shutdown_signal();
+    tokio::pin!(shutdown);
+    loop {
+        tokio::select! {
+            _ = &mut shutdown => return Ok(()),
+            _ = tokio::time::sleep(Duration::from_millis(POLL_DURATION_MS)) => {
+                let mut stdout = std::io::stdout().lock();
+                while let Some(update) = rx.try_recv() {
+                    writeln!(stdout, "{}", update.to_json())?;
+                }
+                stdout.flush()?;
+            }
+        }
+    }
+}
+
+/// Always-on alert watcher: runs feed updates through the same alert
+/// evaluation as the UI and prints each alert as a JSON line, also handing
+/// it to the configured notification sinks. Returns on Ctrl-C or
+/// SIGTERM.
+pub async fn watch_alerts(mut rx: UpdateReceiver) -> Result<()> {
+    let threshold = initial_threshold();
+    let mut coins: HashMap<String, CoinData> = HashMap::new();
+    let mut history = AlertHistory::new(ALERT_HISTORY_CAPACITY);
+    let mut dispatch = AlertDispatch::from_config();
+    let muted = load_muted();
+    log_debug(format!("Watching alerts with threshold {}", threshold));
+
+    let shutdown = shutdown_signal();
+    tokio::pin!(shutdown);
+    loop {
+        tokio::select! {
+            _ = &mut shutdown => {
+                log_debug(format!("Shutting down after {} alerts", history.len()));
+                dispatch.flush().await;
+                return Ok(());
+            }
+            _ = tokio::time::sleep(Duration::from_millis(POLL_DURATION_MS)) => {
+                let mut events = Vec::new();
+                while let Some(update) = rx.try_recv() {
+                    let coin = coins
+                        .entry(update.coin.clone())
+                        .or_insert_with(|| CoinData::new(update.coin.clone()));
+                    events.extend(apply_update(coin, &update, threshold, &muted));
+                }
+
+                let (events, summary) = dispatch.dispatch(events);
+
+                let mut stdout = std::io::stdout().lock();
+                if let Some(summary) = summary {
+                    writeln!(stdout, "{}", json!({ "event": "quiet_hours_summary", "summary": summary }))?;
+                }
+                for event in events {
+                    writeln!(stdout, "{}", event.to_json())?;
+                    history.push(event);
+                }
+                stdout.flush()?;
+            }
+        }
+    }
+}
@@ -1,38 +1,56 @@
-use crate::request::{coin_list_metadata, coin_list_metadate_lighter};
+pub mod check;
+pub mod headless;
+pub mod terminal;
+
+use crate::app::headless::{stream_json, terminal_supported, watch_alerts};
+use crate::config::{
+    EXCHANGE_STATE_FILE, HEALTH_ADDR, INITIAL_EXCHANGE, POLL_DURATION_MS, SHUTDOWN_TIMEOUT_MS,
+    SNAPSHOT_SOCKET, SNAPSHOT_SOCKET_STREAM, UPDATE_CHANNEL_CAPACITY, UPDATE_OVERFLOW_POLICY,
+};
+use crate::data::{UpdateSender, update_channel};
+use crate::exchange::exchanges_for;
+use crate::health::{FeedHealth, serve_health};
+use crate::paths::{data_file, load_state, save_state, write_debug_log};
+use crate::request::FetchError;
+use crate::snapshot::{SharedSnapshot, serve_snapshot};
 use crate::ui::TuiApp;
 use crate::websocket::create_batch_websocket_task;
 use color_eyre::Result;
-use std::fs::OpenOptions;
-use std::io::Write;
 use std::sync::Arc;
 use std::sync::Mutex;
-use tokio::sync::mpsc;
+use std::time::Duration;
+use tokio::sync::{mpsc, watch};
 use tokio::task::JoinSet;
 
 fn log_debug(msg: String) {
-    if let Ok(mut file) = OpenOptions::new()
-        .create(true)
-        .append(true)
-        .open("/tmp/hype_debug.log")
-    {
-        let _ = writeln!(
-            file,
-            "[{}] APP: {}",
-            chrono::Local::now().format("%H:%M:%S"),
-            msg
-        );
-    }
+    write_debug_log("APP", &msg);
 }
 
 #[derive(Debug, Clone)]
 pub struct App {
     current_exchange: Arc<Mutex<u8>>,
+    health: Arc<FeedHealth>,
 }
 
 impl App {
     pub fn new() -> Self {
         Self {
-            current_exchange: Arc::new(Mutex::new(1)),
+            current_exchange: Arc::new(Mutex::new(Self::initial_exchange())),
+            health: Arc::new(FeedHealth::new()),
+        }
+    }
+
+    /// The exchange last used in the app if it was saved and is still a
+    /// known id, otherwise the configured default.
+    fn initial_exchange() -> u8 {
+        let saved = load_state(EXCHANGE_STATE_FILE).and_then(|s| s.parse::<u8>().ok());
+        match saved {
+            Some(exchange @ 1..=3) => exchange,
+            Some(exchange) => {
+                log_debug(format!("Ignoring unknown saved exchange {}", exchange));
+                INITIAL_EXCHANGE
+            }
+            None => INITIAL_EXCHANGE,
         }
     }
 
@@ -40,83 +58,83 @@ impl App {
         *self.current_exchange.lock().unwrap()
     }
 
-    async fn fetch_coin_list(exchange: u8) -> Result<Vec<String>> {
-        match exchange {
-            1 => {
-                // Fetch full coin list from Hyperliquid
-                let coin = coin_list_metadata().await.unwrap();
-                let coins: Vec<String> = coin
-                    .universe
-                    .iter()
-                    .map(|asset| asset.name.clone())
-                    .collect();
-                Ok(coins)
-            }
-            2 => {
-                // Fetch lighter coin list
-                let funding_rates = coin_list_metadate_lighter().await.unwrap();
-                let coins: Vec<String> = funding_rates
-                    .iter()
-                    .map(|rate| rate.symbol.clone())
-                    .collect();
-                Ok(coins)
-            }
-            _ => {
-                // Default: fetch full list
-                let coin = coin_list_metadata().await.unwrap();
-                let coins: Vec<String> = coin
-                    .universe
-                    .iter()
-                    .map(|asset| asset.name.clone())
-                    .collect();
-                Ok(coins)
-            }
+    /// Fetches the coin list shown for an exchange id, from the first venue
+    /// it resolves to.
+    async fn fetch_coin_list(exchange: u8) -> std::result::Result<Vec<String>, FetchError> {
+        let exchanges = exchanges_for(exchange);
+        match exchanges.first() {
+            Some(primary) => primary.coin_list().await,
+            None => Ok(Vec::new()),
         }
     }
 
+    /// Runs ingestion and alerting only, without the UI or health endpoint,
+    /// printing alerts to stdout until shut down.
+    pub async fn run_headless_alerts(&self) -> Result<()> {
+        let exchange = self.get_exchange();
+        let coins = Self::fetch_coin_list(exchange).await?;
+        log_debug(format!(
+            "Headless alerts: watching {} coins on exchange {}",
+            coins.len(),
+            exchange
+        ));
+
+        let (tx, rx) = update_channel(UPDATE_CHANNEL_CAPACITY, UPDATE_OVERFLOW_POLICY);
+        let feed = create_batch_websocket_task(coins, tx, exchange, Arc::clone(&self.health));
+        let result = watch_alerts(rx).await;
+        feed.abort();
+        result
+    }
+
     pub async fn run(&self) -> Result<()> {
-        let (tx, rx) = mpsc::unbounded_channel::<(String, f64, f64, f64, u8)>();
+        let (tx, rx) = update_channel(UPDATE_CHANNEL_CAPACITY, UPDATE_OVERFLOW_POLICY);
 
         // Channel to communicate exchange changes from UI
         let (exchange_tx, mut exchange_rx) = mpsc::unbounded_channel::<u8>();
 
-        // Channel to send coin list updates to UI
-        let (coin_list_tx, coin_list_rx) = mpsc::unbounded_channel::<Vec<String>>();
+        // Channel to send coin list updates to UI, with the feed generation
+        // that streams them
+        let (coin_list_tx, coin_list_rx) = mpsc::unbounded_channel::<(u64, Vec<String>)>();
 
         // Fetch initial coin metadata
         let initial_exchange = self.get_exchange();
         log_debug(format!("Initial exchange value: {}", initial_exchange));
-        let all_coins = Self::fetch_coin_list(initial_exchange).await.unwrap();
+        let all_coins = Self::fetch_coin_list(initial_exchange).await?;
         log_debug(format!(
             "Fetched {} coins for initial exchange {}",
             all_coins.len(),
             initial_exchange
         ));
 
+        // Background tasks, stopped in order through `shutdown_tx` on quit
+        let (shutdown_tx, shutdown_rx) = watch::channel(false);
+        let mut background = JoinSet::new();
+
         // Clone for the websocket management task
+        let mut shutdown_ws = shutdown_rx.clone();
         let tx_clone = tx.clone();
         let coin_list_tx_clone = coin_list_tx.clone();
+        let health_ws = Arc::clone(&self.health);
         let all_coins_for_ws = all_coins.clone();
 
         // Spawn a task to manage websocket subscriptions
-        let ws_manager = tokio::spawn(async move {
+        background.spawn(async move {
             let mut join_set = JoinSet::new();
             let mut last_exchange = initial_exchange;
             let mut current_coins = all_coins_for_ws.clone();
+            // Bumped on every feed restart; the UI drops updates from older ones
+            let mut generation: u64 = 0;
 
             // Helper function to start websockets - inline the logic to avoid lifetime issues
-            let start_websockets =
-                |coins: Vec<String>,
-                 exchange: u8,
-                 tx: mpsc::UnboundedSender<(String, f64, f64, f64, u8)>| {
-                    log_debug("Aborting all existing websocket tasks".to_string());
-                    log_debug(format!(
-                        "Creating new websocket task for exchange {}",
-                        exchange
-                    ));
-                    let task = create_batch_websocket_task(coins, tx, exchange);
-                    async move { task.await.unwrap_or_else(|e| Err(e.into())) }
-                };
+            let start_websockets = |coins: Vec<String>, exchange: u8, tx: UpdateSender| {
+                log_debug("Aborting all existing websocket tasks".to_string());
+                log_debug(format!(
+                    "Creating new websocket task for exchange {}",
+                    exchange
+                ));
+                let task = create_batch_websocket_task(coins, tx, exchange, Arc::clone(&health_ws));
+                async move { task.await.unwrap_or_else(|e| Err(e.into())) }
+            };
 
             // Start initial websockets
             log_debug(format!(
@@ -133,8 +151,11 @@ impl App {
             }
             log_debug("All old tasks stopped".to_string());
 
-            let initial_task =
-                start_websockets(current_coins.clone(), last_exchange, tx_clone.clone());
+            let initial_task = start_websockets(
+                current_coins.clone(),
+                last_exchange,
+                tx_clone.for_generation(generation),
+            );
             join_set.spawn(initial_task);
             log_debug("New websocket task spawned".to_string());
 
@@ -152,8 +173,9 @@ impl App {
                                 Ok(new_coins) => {
                                     log_debug(format!("Fetched {} coins for exchange {}", new_coins.len(), new_exchange));
                                     current_coins = new_coins.clone();
-                                    // Send updated coin list to UI
-                                    let _ = coin_list_tx_clone.send(new_coins.clone());
+                                    // Send updated coin list to UI under a new feed generation
+                                    generation += 1;
+                                    let _ = coin_list_tx_clone.send((generation, new_coins.clone()));
 
                                     // Restart websockets with new coin list and exchange
                                     log_debug(format!("Starting websockets for exchange {}", new_exchange));
@@ -169,19 +191,58 @@ impl App {
                                     }
                                     log_debug("All old tasks stopped".to_string());
 
-                                    let new_task = start_websockets(current_coins.clone(), new_exchange, tx_clone.clone());
+                                    let new_task = start_websockets(current_coins.clone(), new_exchange, tx_clone.for_generation(generation));
                                     join_set.spawn(new_task);
-                                    log_debug("New websocket task spawned".to_string());
+                                    log_debug(format!("New websocket task spawned, generation {}", generation));
                                 }
                                 Err(e) => {
-                                    log_debug(format!("Failed to fetch coin list: {:?}", e));
+                                    log_debug(format!(
+                                        "Failed to fetch coin list: {} (retryable: {})",
+                                        e,
+                                        e.is_retryable()
+                                    ));
                                     // If fetch fails, keep using current coins
                                 }
                             }
                         } else {
-                            log_debug(format!("Exchange unchanged: {}", new_exchange));
+                            // Same exchange again is a forced refresh of its coin list
+                            log_debug(format!("Exchange unchanged: {}, refreshing coin list", new_exchange));
+                            match App::fetch_coin_list(new_exchange).await {
+                                Ok(new_coins) => {
+                                    let changed = new_coins != current_coins;
+                                    log_debug(format!("Refreshed {} coins for exchange {}, changed={}", new_coins.len(), new_exchange, changed));
+                                    if changed {
+                                        generation += 1;
+                                    }
+                                    let _ = coin_list_tx_clone.send((generation, new_coins.clone()));
+
+                                    if changed {
+                                        current_coins = new_coins;
+                                        join_set.abort_all();
+                                        while let Some(result) = join_set.join_next().await {
+                                            log_debug(format!("Drained task: cancelled={}", result.is_err()));
+                                        }
+                                        let new_task = start_websockets(current_coins.clone(), new_exchange, tx_clone.for_generation(generation));
+                                        join_set.spawn(new_task);
+                                        log_debug("Websocket task restarted with refreshed coin list".to_string());
+                                    }
+                                }
+                                Err(e) => {
+                                    log_debug(format!(
+                                        "Failed to refresh coin list: {} (retryable: {})",
+                                        e,
+                                        e.is_retryable()
+                                    ));
+                                }
+                            }
                         }
                     }
+                    _ = shutdown_ws.changed() => {
+                        log_debug("Shutting down websocket tasks".to_string());
+                        join_set.abort_all();
+                        while join_set.join_next().await.is_some() {}
+                        break;
+                    }
                     Some(result) = join_set.join_next() => {
                         match result {
                             Ok(Ok(_)) => {}
@@ -196,33 +257,124 @@ impl App {
                 }
             }
 
-            Ok::<(), color_eyre::Report>(())
+            log_debug("Websocket manager stopped".to_string());
         });
 
+        // Serve the health endpoint alongside the UI when configured
+        if let Some(addr) = HEALTH_ADDR {
+            let health = Arc::clone(&self.health);
+            let shutdown = shutdown_rx.clone();
+            background.spawn(async move {
+                if let Err(e) = serve_health(addr, health, shutdown).await {
+                    log_debug(format!("Health endpoint on {} stopped: {:?}", addr, e));
+                }
+            });
+        }
+
+        // Serve the data snapshot to local clients when configured. Updates
+        // pass through the shared snapshot on their way to the UI.
+        let rx = match SNAPSHOT_SOCKET {
+            Some(path) => {
+                let snapshot = Arc::new(SharedSnapshot::new());
+                let (ui_tx, ui_rx) =
+                    update_channel(UPDATE_CHANNEL_CAPACITY, UPDATE_OVERFLOW_POLICY);
+                let mut feed_rx = rx;
+                let tapped = Arc::clone(&snapshot);
+                let mut shutdown_tap = shutdown_rx.clone();
+                background.spawn(async move {
+                    loop {
+                        tokio::select! {
+                            _ = tokio::time::sleep(Duration::from_millis(POLL_DURATION_MS)) => {
+                                while let Some(update) = feed_rx.try_recv() {
+                                    tapped.record(&update);
+                                    ui_tx.try_send(update);
+                                }
+                            }
+                            _ = shutdown_tap.changed() => break,
+                        }
+                    }
+                });
+
+                let path = data_file(path);
+                let shutdown = shutdown_rx.clone();
+                background.spawn(async move {
+                    if let Err(e) =
+                        serve_snapshot(&path, snapshot, SNAPSHOT_SOCKET_STREAM, shutdown).await
+                    {
+                        log_debug(format!(
+                            "Snapshot socket {} stopped: {:?}",
+                            path.display(),
+                            e
+                        ));
+                    }
+                });
+                ui_rx
+            }
+            None => rx,
+        };
+
         // Get initial coin list for UI
         let initial_coin_list = all_coins.clone();
 
         // Create UI task with exchange sender
         let current_exchange_ui = Arc::clone(&self.current_exchange);
-        let ui_task = tokio::spawn(async move {
-            let terminal = ratatui::init();
-            let app = TuiApp::new(
-                initial_coin_list.clone(),
-                current_exchange_ui,
-                exchange_tx,
-                initial_coin_list,
-                coin_list_rx,
+        let health_ui = Arc::clone(&self.health);
+        let tui = terminal_supported();
+        let ui_task = if tui {
+            tokio::spawn(async move {
+                let terminal = terminal::init()?;
+                let mut app = TuiApp::new(
+                    initial_coin_list,
+                    current_exchange_ui,
+                    exchange_tx,
+                    coin_list_rx,
+                    health_ui,
+                );
+                // The terminal is restored once background tasks have stopped
+                let result = app.run(terminal, rx);
+                app.flush_alerts().await;
+                result
+            })
+        } else {
+            // No TTY or TERM=dumb: the full-screen UI would garble the
+            // output, so stream updates as JSON lines instead
+            log_debug("Terminal unsupported, streaming JSON instead of the UI".to_string());
+            eprintln!(
+                "hype: stdout is not a capable terminal (no TTY or TERM=dumb); \
+                 streaming updates as JSON lines, Ctrl-C to stop"
             );
-            let app_result = app.run(terminal, rx);
-            ratatui::restore();
-            app_result
-        });
+            tokio::spawn(stream_json(rx))
+        };
 
         // Wait for UI to finish (user quits)
         let ui_result = ui_task.await;
 
-        // Cancel websocket manager when UI exits
-        ws_manager.abort();
+        // Signal every background task to stop and give them a bounded time
+        // to finish before aborting whatever is left
+        let _ = shutdown_tx.send(true);
+        let stopped = tokio::time::timeout(Duration::from_millis(SHUTDOWN_TIMEOUT_MS), async {
+            while background.join_next().await.is_some() {}
+        })
+        .await;
+        if stopped.is_err() {
+            log_debug(format!(
+                "{} background tasks still running after {}ms, aborting",
+                background.len(),
+                SHUTDOWN_TIMEOUT_MS
+            ));
+            background.abort_all();
+            while background.join_next().await.is_some() {}
+        }
+
+        // Remember the exchange for the next start
+        let exchange = self.get_exchange();
+        if let Err(e) = save_state(EXCHANGE_STATE_FILE, &exchange.to_string()) {
+            log_debug(format!("Failed to save exchange {}: {}", exchange, e));
+        }
+
+        if tui {
+            terminal::restore();
+        }
 
         match ui_result {
             Ok(Ok(_)) => {}
@@ -0,0 +1,113 @@
+use std::io;
+use std::panic::PanicHookInfo;
+use std::sync::atomic::{AtomicBool, Ordering};
+
+use color_eyre::Result;
+use ratatui::crossterm::execute;
+use ratatui::crossterm::terminal::{EnterAlternateScreen, enable_raw_mode};
+use ratatui::prelude::CrosstermBackend;
+use ratatui::{DefaultTerminal, Terminal};
+
+use crate::config::RESTORE_TERMINAL_ON_PANIC;
+
+/// Set while the full-screen UI holds the terminal in raw mode on the
+/// alternate screen. Headless modes never set it, so a panic there leaves
+/// the terminal alone.
+static TUI_ACTIVE: AtomicBool = AtomicBool::new(false);
+
+type PanicHook = Box<dyn Fn(&PanicHookInfo<'_>) + Send + Sync + 'static>;
+
+/// Enters raw mode and the alternate screen for the UI. Unlike
+/// `ratatui::init` this installs no panic hook of its own; the one from
+/// `install_hooks` restores the terminal while the UI is active.
+pub fn init() -> io::Result<DefaultTerminal> {
+    enable_raw_mode()?;
+    TUI_ACTIVE.store(true, Ordering::SeqCst);
+    execute!(io::stdout(), EnterAlternateScreen)?;
+    Terminal::new(CrosstermBackend::new(io::stdout()))
+}
+
+/// Leaves raw mode and the alternate screen, if the UI entered them.
+pub fn restore() {
+    if TUI_ACTIVE.swap(false, Ordering::SeqCst) {
+        ratatui::restore();
+    }
+}
+
+/// Installs `color_eyre`'s error and panic hooks, wrapping the panic hook so
+/// an active UI is restored before the report is printed.
+pub fn install_hooks() -> Result<()> {
+    let (panic_hook, eyre_hook) = color_eyre::config::HookBuilder::default().into_hooks();
+    eyre_hook.install()?;
+    if !RESTORE_TERMINAL_ON_PANIC {
+        panic_hook.install();
+        return Ok(());
+    }
+    std::panic::set_hook(restoring(
+        &TUI_ACTIVE,
+        ratatui::restore,
+        panic_hook.into_panic_hook(),
+    ));
+    Ok(())
+}
+
+/// Wraps `report` so that, when `active` is set, `restore` runs first and
+/// only once.
+fn restoring(active: &'static AtomicBool, restore: fn(), report: PanicHook) -> PanicHook {
+    Box::new(move |info| {
+        if active.swap(false, Ordering::SeqCst) {
+            restore();
+        }
+        report(info);
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::atomic::AtomicUsize;
+
+    static ACTIVE: AtomicBool = AtomicBool::new(false);
+    static RESTORES: AtomicUsize = AtomicUsize::new(0);
+    static REPORTS: AtomicUsize = AtomicUsize::new(0);
+
+    fn count_restore() {
+        RESTORES.fetch_add(1, Ordering::SeqCst);
+    }
+
+    fn panic_with_hook() {
+        let previous = std::panic::take_hook();
+        std::panic::set_hook(restoring(
+            &ACTIVE,
+            count_restore,
+            Box::new(|_| {
+                REPORTS.fetch_add(1, Ordering::SeqCst);
+            }),
+        ));
+        let result = std::panic::catch_unwind(|| panic!("intentional test panic"));
+        std::panic::set_hook(previous);
+        assert!(result.is_err());
+    }
+
+    // One test, since panic hooks are process-wide
+    #[test]
+    fn hook_restores_only_an_active_ui_before_reporting() {
+        // Headless: nothing to restore, the panic is still reported
+        ACTIVE.store(false, Ordering::SeqCst);
+        panic_with_hook();
+        assert_eq!(RESTORES.load(Ordering::SeqCst), 0);
+        assert_eq!(REPORTS.load(Ordering::SeqCst), 1);
+
+        // UI active: restored once, then reported
+        ACTIVE.store(true, Ordering::SeqCst);
+        panic_with_hook();
+        assert_eq!(RESTORES.load(Ordering::SeqCst), 1);
+        assert_eq!(REPORTS.load(Ordering::SeqCst), 2);
+        assert!(!ACTIVE.load(Ordering::SeqCst));
+
+        // A second panic finds the terminal already restored
+        panic_with_hook();
+        assert_eq!(RESTORES.load(Ordering::SeqCst), 1);
+        assert_eq!(REPORTS.load(Ordering::SeqCst), 3);
+    }
+}
@@ -1,5 +1,16 @@
+use ratatui::style::Color;
 use ratatui::style::palette::tailwind;
 
+use crate::data::{
+    CarryWeights, DivergenceRule, FundingRateRound, HYPERLIQUID, LIGHTER, OverflowPolicy, Position,
+};
+use crate::time::Timezone;
+use crate::ui::{
+    ColorMode, Column, ColumnWidths, FavoritesSort, GroupBy, Highlight, InitialSelection,
+    SearchScope, SelectionStyle, TabConfig,
+};
+use crate::websocket::{SubscribeOrder, SubscriptionLog};
+
 pub const PALETTES: [tailwind::Palette; 4] = [
     tailwind::BLUE,
     tailwind::EMERALD,
@@ -8,11 +19,327 @@ pub const PALETTES: [tailwind::Palette; 4] = [
 ];
 
 pub const INFO_TEXT: [&str; 2] = [
-    "(Esc) quit | (↑/↓) move row | (←/→) move col",
-    "(Shift + →/←) cycle color",
+    "(Esc) quit | (↑/↓) move row | (PgUp/PgDn) page | (←/→) move col",
+    "(Shift + →/←) cycle color | (?) all keys",
+];
+
+// Where logs, exports and saved state live. `HYPE_DATA_DIR` takes precedence;
+// when neither is set, `$XDG_DATA_HOME/hype` or `~/.local/share/hype` is used.
+pub const DATA_DIR: Option<&str> = None;
+pub const DATA_DIR_ENV: &str = "HYPE_DATA_DIR";
+pub const DEBUG_LOG_FILE: &str = "hype_debug.log";
+// Subscription detail written to the debug log, for diagnosing coins that
+// don't stream: failures only, every subscription sent to a venue and its
+// outcome, or that plus the venue's acks and control frames verbatim
+pub const SUBSCRIPTION_LOG_LEVEL: SubscriptionLog = SubscriptionLog::Failures;
+// Key that writes a diagnostic snapshot for bug reports to the data directory,
+// including the last DIAGNOSTICS_LOG_LINES lines of the debug log. Keys the
+// table already binds (see `?`) take precedence over this and OPEN_TRADE_PAGE_KEY
+pub const DIAGNOSTICS_KEY: char = '!';
+pub const DIAGNOSTICS_LOG_LINES: usize = 200;
+// Key that opens the selected coin's trading page in the browser, built from
+// the row's venue template with `{symbol}` replaced by the venue's symbol.
+// Without a browser (e.g. over SSH) the URL is copied to the clipboard.
+pub const OPEN_TRADE_PAGE_KEY: char = 'u';
+pub const TRADE_URL_TEMPLATES: &[(u8, &str)] = &[
+    (HYPERLIQUID, "https://app.hyperliquid.xyz/trade/{symbol}"),
+    (LIGHTER, "https://app.lighter.xyz/trade/{symbol}"),
 ];
+// Named layouts saved from the app (`S`) and listed by the picker (`L`),
+// stored as JSON in the data directory
+pub const LAYOUTS_STATE_FILE: &str = "hype_layouts";
+// Layout applied at startup, by name, e.g. Some("arb scan")
+pub const INITIAL_LAYOUT: Option<&str> = None;
+// Timezone for displayed times, log lines and export filenames. Funding
+// settles on UTC boundaries, which is why UTC is the default.
+pub const TIMEZONE: Timezone = Timezone::Utc;
 
 pub const ITEM_HEIGHT: usize = 2;
 pub const POLL_DURATION_MS: u64 = 50;
+// Upper bound on table redraws per second. Updates arriving faster than this
+// are coalesced into the next frame; input is still read promptly. 0 redraws
+// on every loop iteration.
+pub const MAX_FPS: u32 = 30;
+// Clear the terminal and redraw everything after a resize, for terminals
+// that leave stale cells behind when the window changes size
+pub const CLEAR_ON_RESIZE: bool = true;
+// Default hourly funding fraction above which rates are highlighted and
+// alerted on. Changing it in the app (`=`) saves it to `THRESHOLD_STATE_FILE`.
 pub const FUNDING_RATE_THRESHOLD: f64 = 0.000013;
+pub const THRESHOLD_STATE_FILE: &str = "hype_threshold";
+// Exchange to start on: 1 Hyperliquid, 2 Lighter, 3 both. The exchange last
+// used in the app is saved to `EXCHANGE_STATE_FILE` and takes precedence.
+pub const INITIAL_EXCHANGE: u8 = 1;
+pub const EXCHANGE_STATE_FILE: &str = "hype_exchange";
+// Coins muted from alerts in the app (`M`) are saved here; mark them with 🔕
+pub const MUTED_STATE_FILE: &str = "hype_muted";
+pub const SHOW_MUTED_MARKER: bool = true;
+// Favorites (`*`) stay at the top of the table and are saved here. In manual
+// order they keep the order they were added, moved with `K`/`J`; in global
+// order they follow the active sort column.
+pub const FAVORITES_STATE_FILE: &str = "hype_favorites";
+pub const FAVORITES_SORT: FavoritesSort = FavoritesSort::Manual;
+// Field rows are grouped by when grouping is on (`X`): funding sign, venue,
+// first letter or favorites. Each group gets a separator row with its coin
+// count and average funding; rows keep the sort order within their group.
+pub const GROUP_BY: GroupBy = GroupBy::FundingSign;
+pub const GROUP_ROWS: bool = false;
+// Row the cursor lands on at startup: the first row, the first favorite, or
+// the coin with the largest absolute funding once data arrives
+pub const INITIAL_SELECTION: InitialSelection = InitialSelection::FirstRow;
+// Hourly funding fractions beyond this magnitude are treated as bad data:
+// marked with `?`, sorted last and kept out of alerts and ranks
+pub const MAX_SANE_HOURLY_FUNDING: f64 = 0.05;
+// Alert when the hourly funding gap between venues crosses this fraction,
+// in either direction; needs the combined view. None turns arb alerts off.
+pub const ARB_ALERT_SPREAD: Option<f64> = Some(0.00005);
+// Hours over which the mark-to-oracle basis is assumed to converge when the
+// basis column scales it like funding (Hyperliquid averages premium over 8h)
+pub const BASIS_PERIOD_HOURS: f64 = 8.0;
+// Notional in USD for the daily yield column (`D`): funding paid per day on it
+pub const YIELD_NOTIONAL_USD: f64 = 1000.0;
+// Your cost of capital as an annual fraction (0.08 = 8% APR). With the net
+// view on (`N`), funding is colored against it and a net column is shown.
+pub const BASELINE_APR: f64 = 0.0;
+pub const SHOW_NET_OF_BASELINE: bool = false;
+// How long search results and other notices stay on screen
 pub const ERROR_POPUP_DURATION_MS: u64 = 1500;
+pub const FUNDING_FLASH_DURATION_MS: u64 = 600;
+// A coin's price counts as stale once no valid price has arrived for this
+// long, even while funding keeps updating. USD open interest computed from a
+// stale price is marked with `~`.
+pub const PRICE_STALE_SECS: u64 = 60;
+// Whole-row flash when a coin's funding crosses the alert threshold in either
+// direction; the row is also bold for the first half (0 disables)
+pub const THRESHOLD_FLASH_DURATION_MS: u64 = 2000;
+pub const THRESHOLD_FLASH_COLOR: Color = tailwind::AMBER.c800;
+// Right-align numeric columns so values line up on the decimal point
+pub const ALIGN_NUMERIC_COLUMNS: bool = true;
+// Draw a vertical divider between table columns
+pub const COLUMN_SEPARATORS: bool = false;
+// How columns share the table width: equally, by typical content width, or
+// from COLUMN_WIDTHS, e.g. &[(Column::Coin, 8), (Column::Funding, 16)]
+pub const COLUMN_WIDTH_STRATEGY: ColumnWidths = ColumnWidths::Content;
+pub const COLUMN_WIDTHS: &[(Column, u16)] = &[];
+pub const REPEAT_HEADER: bool = false;
+pub const REPEAT_HEADER_INTERVAL: usize = 20;
+pub const ALERT_HISTORY_CAPACITY: usize = 1000;
+// Set to e.g. Some("alerts.csv") to append alerts to a file in the data directory as they fire
+pub const ALERT_LOG_FILE: Option<&str> = None;
+pub const ALERT_LOG_MAX_BYTES: u64 = 5 * 1024 * 1024;
+// UTC windows as "HH:MM-HH:MM" (wrapping midnight is fine, e.g. "22:00-07:00";
+// equal ends like "00:00-00:00" mean all day) during which alerts are not
+// printed, posted, logged or recorded
+pub const ALERT_QUIET_HOURS: &[&str] = &[];
+// Hold alerts from quiet hours and, when the window ends, record them and send
+// the sinks one summary instead of dropping them. At most ALERT_HISTORY_CAPACITY
+// are kept, the summary still counts all of them
+pub const QUEUE_QUIET_ALERTS: bool = true;
+// Run without the UI, printing alerts as JSON lines (same as `--headless-alerts`)
+pub const HEADLESS_ALERTS: bool = false;
+// While the UI is up, leave raw mode and the alternate screen before a panic
+// is reported, so a crash prints a readable backtrace to a usable terminal
+pub const RESTORE_TERMINAL_ON_PANIC: bool = true;
+// Each alert is POSTed here as JSON when set
+pub const ALERT_WEBHOOK_URL: Option<&str> = None;
+// Also show each alert as a desktop notification (notify-send on Linux,
+// osascript on macOS)
+pub const DESKTOP_NOTIFICATIONS: bool = false;
+// Collect alerts for this long and send the webhook/Telegram/desktop sinks one
+// summary ("5 coins crossed threshold: ...") instead of a message each; 0 sends
+// each. A batch still open on quit is sent as it stands.
+pub const ALERT_BATCH_WINDOW_SECS: u64 = 0;
+// A coin notifies the sinks about the same kind of alert at most once per
+// cooldown, in the UI and headless alike; the alert history keeps every event
+pub const ALERT_COOLDOWN_SECS: u64 = 300;
+// Each alert is also sent from this Telegram bot to the chat when both are set,
+// from the UI and from the headless watcher
+pub const TELEGRAM_BOT_TOKEN: Option<&str> = None;
+pub const TELEGRAM_CHAT_ID: Option<&str> = None;
+pub const WRAP_NAVIGATION: bool = true;
+// Rows from the previous page kept on screen by PageUp/PageDown
+pub const PAGE_OVERLAP_ROWS: usize = 1;
+// Display-board mode (`A`): page through the table on a timer, wrapping at the
+// end. A keypress pauses it for AUTO_SCROLL_PAUSE_MS.
+pub const AUTO_SCROLL: bool = false;
+pub const AUTO_SCROLL_INTERVAL_MS: u64 = 5000;
+pub const AUTO_SCROLL_PAUSE_MS: u64 = 30_000;
+// First entry is the default scope; Tab in the search popup cycles through the rest
+pub const SEARCH_SCOPES: &[SearchScope] = &[
+    SearchScope::Coin,
+    SearchScope::Funding,
+    SearchScope::OpenInterest,
+];
+// Round age column (`a`): time since the source venue's funding stamp.
+// Neither venue sends a per-message timestamp, so this is the age of the
+// current funding round (Lighter's `funding_timestamp`) and normally runs up
+// to one funding interval; Hyperliquid sends no stamp and shows N/A. Turns
+// yellow once the round is overdue by this many seconds, red an interval later.
+pub const VENUE_AGE_WARN_SECS: f64 = 300.0;
+// Subscribe to only this many coins at launch; the rest follow in the background
+pub const INITIAL_SUBSCRIBE_LIMIT: Option<usize> = None;
+pub const SUBSCRIBE_ORDER: SubscribeOrder = SubscribeOrder::Priority;
+pub const SUBSCRIBE_PRIORITY: &[&str] = &["BTC", "ETH", "SOL", "HYPE", "XRP", "DOGE", "BNB"];
+pub const BACKGROUND_SUBSCRIBE_BATCH: usize = 10;
+// Upper bound on a single websocket connect or subscribe before it counts as
+// a failed attempt and backs off
+pub const CONNECT_TIMEOUT_SECS: u64 = 10;
+// Reuse one Hyperliquid REST client (and one HTTP connection pool for the
+// perp universe request) for metadata fetches instead of setting up a fresh
+// one each time, e.g. on every exchange switch
+pub const SHARE_INFO_CLIENT: bool = true;
+// Bound on each step of the `--check` connectivity self-test
+pub const CHECK_TIMEOUT_SECS: u64 = 5;
+// Websocket keepalive: ping a quiet connection this often, and reconnect
+// when nothing at all arrives within the read timeout. The ping interval must
+// be shorter than the read timeout; otherwise half the timeout is used.
+pub const WS_PING_INTERVAL_SECS: u64 = 30;
+pub const WS_READ_TIMEOUT_SECS: u64 = 60;
+// Most coin updates buffered between the feeds and the UI. When the UI falls
+// behind, updates are dropped per the overflow policy instead of growing memory.
+pub const UPDATE_CHANNEL_CAPACITY: usize = 10_000;
+pub const UPDATE_OVERFLOW_POLICY: OverflowPolicy = OverflowPolicy::DropOldest;
+// Lighter markets to stream, by symbol. Each gets its own `market_stats/<id>`
+// channel; empty streams every market through `market_stats/all`.
+pub const LIGHTER_WATCHLIST: &[&str] = &[];
+// In combined mode each row shows the first venue in this list that has data
+// for the coin, falling back down the list; unlisted venues come last
+pub const VENUE_PRIORITY: &[u8] = &[HYPERLIQUID, LIGHTER];
+// How often to re-fetch Lighter's market list so newly listed market ids get
+// their symbols mid-session (0 disables)
+pub const LIGHTER_MARKET_REFRESH_SECS: u64 = 300;
+// Show (1 + hourly)^hours - 1 instead of hourly * hours
+pub const COMPOUND_ANNUALIZATION: bool = false;
+// Decimals shown for funding percentages in each period; longer periods have
+// larger values and need fewer. Periods missing here use 6.
+pub const FUNDING_DECIMALS: &[(FundingRateRound, usize)] = &[
+    (FundingRateRound::Hourly, 6),
+    (FundingRateRound::QuadriHourly, 5),
+    (FundingRateRound::OctaHourly, 5),
+    (FundingRateRound::Daily, 4),
+    (FundingRateRound::Monthly, 3),
+    (FundingRateRound::Annually, 2),
+];
+// Periods shown side by side when multi-period funding is on (`F`), in place
+// of the single funding column that `r` cycles; all derive from the hourly rate
+pub const FUNDING_PERIODS: &[FundingRateRound] = &[
+    FundingRateRound::Hourly,
+    FundingRateRound::OctaHourly,
+    FundingRateRound::Annually,
+];
+pub const MULTI_PERIOD_FUNDING: bool = false;
+// Serve GET /health on this address, e.g. Some("0.0.0.0:8080")
+pub const HEALTH_ADDR: Option<&str> = None;
+// Serve the latest data as a JSON line on this Unix socket, relative to the
+// data directory unless absolute, e.g. Some("hype.sock"). With streaming on,
+// clients then get every update as a JSON line until they disconnect.
+pub const SNAPSHOT_SOCKET: Option<&str> = None;
+pub const SNAPSHOT_SOCKET_STREAM: bool = false;
+// How long background tasks get to stop cleanly on quit before being aborted
+pub const SHUTDOWN_TIMEOUT_MS: u64 = 2000;
+pub const HEALTH_MAX_UPDATE_AGE_SECS: u64 = 30;
+// Weight of the newest update in the funding EMA; smaller is smoother
+pub const FUNDING_EMA_ALPHA: f64 = 0.1;
+pub const SMOOTH_FUNDING: bool = false;
+pub const ALERTS_USE_SMOOTHED: bool = false;
+// Venue-native symbol -> canonical symbol, matched case-insensitively
+pub const SYMBOL_ALIASES: &[(&str, &str)] = &[
+    ("XBT", "BTC"),
+    ("1000PEPE", "kPEPE"),
+    ("1000SHIB", "kSHIB"),
+    ("1000BONK", "kBONK"),
+    ("1000FLOKI", "kFLOKI"),
+    ("1000LUNC", "kLUNC"),
+    ("1000SATS", "kSATS"),
+    ("1000NEIRO", "kNEIRO"),
+];
+// Stripped from the end of native symbols, e.g. BTCUSDT -> BTC
+pub const SYMBOL_QUOTE_SUFFIXES: &[&str] = &["-PERP", "/USDC", "/USD", "-USD", "USDT"];
+// Stablecoins and pegged assets, by canonical symbol. Their funding sits near
+// zero, so they are hidden unless re-included with `Z`.
+pub const PEGGED_COINS: &[&str] = &["USDC", "USDE", "FDUSD", "DAI", "USD1", "PYUSD"];
+pub const HIDE_PEGGED: bool = true;
+// Dim every column except the one the table was last sorted by
+pub const DIM_UNSORTED_COLUMNS: bool = false;
+// Cursor highlight styles. Use `Highlight::Explicit { fg, bg }` where reverse
+// video is unreadable on the terminal theme.
+pub const SELECTION_STYLE: SelectionStyle = SelectionStyle {
+    row: Highlight::Reverse,
+    column: Highlight::Accent,
+    cell: Highlight::Reverse,
+};
+// What funding colors show at startup, switched with `G`: sign (red below
+// zero or the baseline, green past the threshold) or magnitude (shades of
+// `MAGNITUDE_HUE`, more intense the further from the baseline either way)
+pub const COLOR_MODE: ColorMode = ColorMode::Signed;
+pub const MAGNITUDE_HUE: tailwind::Palette = tailwind::AMBER;
+// Watchlist tabs; a tab with no coins lists everything
+pub const TABS: &[TabConfig] = &[
+    TabConfig {
+        name: "All",
+        coins: &[],
+    },
+    TabConfig {
+        name: "Majors",
+        coins: &["BTC", "ETH", "SOL", "HYPE", "XRP", "BNB"],
+    },
+];
+pub const FUNDING_RANK_REFRESH_MS: u64 = 5000;
+// Per-coin funding history keeps at most one sample (the last) per interval,
+// covering the window; the detail popup lists it when enabled. Intervals
+// under a second are raised to one.
+pub const FUNDING_HISTORY_INTERVAL_SECS: u64 = 5;
+pub const FUNDING_HISTORY_WINDOW_SECS: u64 = 600;
+pub const DETAIL_HISTORY: bool = true;
+// Funding histogram (`H`): shown coins bucketed by annualized funding, in
+// bins of `HISTOGRAM_BIN_APR` over `HISTOGRAM_RANGE_APR` (fractions, so 0.05
+// is 5% APR). Rates outside the range are counted in the end bins.
+pub const HISTOGRAM_BIN_APR: f64 = 0.1;
+pub const HISTOGRAM_RANGE_APR: (f64, f64) = (-1.0, 2.0);
+// Carry score column (`Y`), ranking coins by risk-adjusted funding:
+//   funding * |funding APR| + liquidity * log10(OI USD / $1M)
+//     - volatility * 24h range
+// APR and range are fractions (0.25 = 25%); the range is 24h high / low - 1,
+// or the absolute 24h change on venues without a range. OI under $1M adds
+// nothing. With the defaults a coin paying 50% APR on $100M OI that moved 5%
+// scores 0.5 + 0.2 - 0.05 = 0.65.
+pub const CARRY_WEIGHTS: CarryWeights = CarryWeights {
+    funding: 1.0,
+    liquidity: 0.1,
+    volatility: 1.0,
+};
+// Cells in the funding countdown bar (`g`), which fills up as the current
+// funding interval nears settlement
+pub const COUNTDOWN_BAR_WIDTH: usize = 8;
+// Divergence column (`z`): how far each coin's funding is from its own average
+// over the funding history above, from at least this many samples. Coins at
+// or beyond the rule (a z-score, or a gap as an hourly fraction) are
+// highlighted; the column sorts by the size of the divergence.
+pub const DIVERGENCE_RULE: DivergenceRule = DivergenceRule::ZScore(2.0);
+pub const DIVERGENCE_MIN_SAMPLES: usize = 12;
+// Trend arrows compare funding with the value this long ago, ignoring changes
+// smaller than the dead band (an hourly fraction)
+pub const TREND_LOOKBACK_SECS: u64 = 60;
+pub const TREND_DEAD_BAND: f64 = 0.000001;
+// Keep the cursor on the same coin when live updates re-sort the table
+pub const FOLLOW_SELECTION: bool = true;
+// Column the table is sorted by at startup. Sorting waits SORT_GRACE_MS after
+// startup or an exchange switch so the first order reflects real data rather
+// than the zeros rows start with.
+pub const DEFAULT_SORT_COLUMN: Option<Column> = Some(Column::Funding);
+pub const SORT_GRACE_MS: u64 = 3000;
+// Pop a notice listing added and removed coins after a forced coin-list refresh
+pub const SHOW_LISTING_CHANGES: bool = true;
+// Show the OI-weighted and median funding across coins in the footer
+pub const SHOW_MARKET_INDEX: bool = false;
+// Show open interest in coins and USD together, e.g. `1,234 BTC ($148.00M)`,
+// instead of one unit at a time (`t`). Toggle with `T` on narrow terminals.
+pub const OI_BOTH_UNITS: bool = false;
+// Abbreviate every large number with K/M/B like USD open interest, e.g. native
+// open interest and the funding bill, to keep the table narrow (`C` toggles)
+pub const COMPACT_NUMBERS: bool = false;
+// Coins you hold, e.g. `Position { coin: "BTC", side: Side::Long, size: Some(0.5) }`
+// with `crate::data::Side` imported. Their coin cells are red while the position
+// pays funding and green while it receives; positions with a size add up to a
+// funding bill in the footer.
+pub const POSITIONS: &[Position] = &[];
@@ -0,0 +1,87 @@
+use crate::data::{CoinData, FundingRateRound, is_valid_price};
+
+/// Weights of the carry score terms, configured in `CARRY_WEIGHTS`.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct CarryWeights {
+    /// Per unit of absolute funding APR (1.0 = 100%).
+    pub funding: f64,
+    /// Per decade of USD open interest above $1M.
+    pub liquidity: f64,
+    /// Per unit of 24h price range (1.0 = 100%), subtracted.
+    pub volatility: f64,
+}
+
+/// What a coin's carry score is computed from.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct CarryInputs {
+    /// Absolute funding, annualized without compounding, as a fraction.
+    pub funding_apr: f64,
+    pub open_interest_usd: f64,
+    /// 24h high over low minus one, or the absolute 24h change where the
+    /// venue reports no range, as a fraction.
+    pub volatility: f64,
+}
+
+impl CarryInputs {
+    /// Gathers the inputs, or `None` while funding is suspect or the price,
+    /// OI or 24h movement is still unknown.
+    pub fn for_coin(coin: &CoinData, smoothed: bool) -> Option<Self> {
+        if coin.is_funding_suspect() {
+            return None;
+        }
+        let volatility = match (coin.day_range, coin.daily_change_pct) {
+            (Some((low, high)), _) if is_valid_price(low) => high / low - 1.0,
+            (_, Some(change)) => change.abs() / 100.0,
+            _ => return None,
+        };
+        Some(Self {
+            funding_apr: coin.hourly_funding_value(smoothed).abs()
+                * FundingRateRound::Annually.hours(),
+            open_interest_usd: coin.open_interest_usd()?,
+            volatility,
+        })
+    }
+
+    /// `funding * |APR| + liquidity * log10(OI USD / $1M) - volatility * range`.
+    pub fn score(&self, weights: &CarryWeights) -> f64 {
+        weights.funding * self.funding_apr
+            + weights.liquidity * (self.open_interest_usd / 1_000_000.0).max(1.0).log10()
+            - weights.volatility * self.volatility
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::data::{CoinUpdate, HYPERLIQUID};
+
+    const WEIGHTS: CarryWeights = CarryWeights {
+        funding: 1.0,
+        liquidity: 0.1,
+        volatility: 1.0,
+    };
+
+    fn coin_at(price: f64) -> CoinData {
+        let mut coin = CoinData::new("BTC".to_string());
+        coin.apply(
+            &CoinUpdate::new("BTC".to_string(), 0.0001, 10.0, price, 1, HYPERLIQUID)
+                .with_daily_change(5.0)
+                .with_day_range(0.0, 0.0),
+        );
+        coin
+    }
+
+    #[test]
+    fn no_score_without_a_usable_price() {
+        for price in [0.0, f64::NAN, f64::INFINITY] {
+            assert_eq!(CarryInputs::for_coin(&coin_at(price), false), None);
+        }
+    }
+
+    #[test]
+    fn zero_day_range_falls_back_to_daily_change() {
+        let inputs = CarryInputs::for_coin(&coin_at(50_000.0), false).unwrap();
+        assert_eq!(inputs.volatility, 0.05);
+        assert!(inputs.score(&WEIGHTS).is_finite());
+    }
+}
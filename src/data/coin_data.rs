@@ -1,24 +1,81 @@
+use std::collections::BTreeMap;
+use std::time::{Duration, Instant};
+
+use crate::config::{
+    ALERTS_USE_SMOOTHED, BASIS_PERIOD_HOURS, FUNDING_EMA_ALPHA, FUNDING_HISTORY_INTERVAL_SECS,
+    FUNDING_HISTORY_WINDOW_SECS, MAX_SANE_HOURLY_FUNDING, PRICE_STALE_SECS, VENUE_PRIORITY,
+};
+use crate::data::{
+    CoinUpdate, FundingHistory, FundingSample, VenueQuote, funding_countdown, hourly_fraction,
+    is_valid_price,
+};
+
 #[derive(Clone, Debug)]
 pub struct CoinData {
+    /// Canonical symbol shared across venues.
     pub coin: String,
+    /// Symbol as named by the venue that last updated this coin.
+    pub native_symbol: Option<String>,
     pub funding: f64,
     pub open_interest: f64,
     pub oracle_price: f64,
+    pub mark_price: f64,
+    /// Spot (or index) price from the source venue, `None` where it has none.
+    pub spot_price: Option<f64>,
+    /// Last traded price on the source venue, `None` where it isn't reported.
+    pub last_trade_price: Option<f64>,
+    /// 24h price change in percent, from the last venue that reported it.
+    pub daily_change_pct: Option<f64>,
+    /// 24h low and high price, when the venue reports them.
+    pub day_range: Option<(f64, f64)>,
     pub current_exchange: u8,
+    /// Venue the row values come from; in combined mode the first venue in
+    /// `VENUE_PRIORITY` with data.
+    pub source_venue: Option<u8>,
+    pub venue_timestamp_ms: Option<i64>,
+    pub funding_changed_at: Option<Instant>,
+    /// When funding and a usable price last arrived. Venues can refresh
+    /// them on different cadences, so each is tracked separately.
+    pub funding_updated_at: Option<Instant>,
+    pub price_updated_at: Option<Instant>,
+    /// Exponential moving average of funding, seeded by the first update.
+    pub funding_ema: Option<f64>,
+    /// Latest values per venue, keyed by venue id.
+    pub quotes: BTreeMap<u8, VenueQuote>,
+    /// Recent funding values, one per `FUNDING_HISTORY_INTERVAL_SECS`.
+    pub history: FundingHistory,
 }
 
 impl CoinData {
     pub fn new(coin: String) -> Self {
         Self {
             coin,
+            native_symbol: None,
             funding: 0.0,
             open_interest: 0.0,
             oracle_price: 0.0,
+            mark_price: 0.0,
+            spot_price: None,
+            last_trade_price: None,
+            daily_change_pct: None,
+            day_range: None,
             current_exchange: 0,
+            source_venue: None,
+            venue_timestamp_ms: None,
+            funding_changed_at: None,
+            funding_updated_at: None,
+            price_updated_at: None,
+            funding_ema: None,
+            quotes: BTreeMap::new(),
+            history: FundingHistory::downsampled(
+                Duration::from_secs(FUNDING_HISTORY_INTERVAL_SECS),
+                Duration::from_secs(FUNDING_HISTORY_WINDOW_SECS),
+            ),
         }
     }
 
     pub fn update(&mut self, funding: f64, open_interest: f64, oracle_price: f64) {
+        self.update_ema(funding);
         self.funding = funding;
         self.open_interest = open_interest;
         self.oracle_price = oracle_price;
@@ -31,12 +88,250 @@ impl CoinData {
         oracle_price: f64,
         exchange: u8,
     ) {
+        self.update_ema(funding);
         self.funding = funding;
         self.open_interest = open_interest;
         self.oracle_price = oracle_price;
         self.current_exchange = exchange;
     }
 
+    pub fn apply(&mut self, update: &CoinUpdate) {
+        // In combined mode a lower-priority venue only fills in while no
+        // venue ahead of it has data; its quote is cached either way
+        if update.exchange == 3 && self.has_preferred_quote(update.venue) {
+            self.quotes
+                .insert(update.venue, VenueQuote::from_update(update));
+            return;
+        }
+        if self.source_venue != Some(update.venue) {
+            // Venues quote in different units, so the average restarts
+            self.funding_ema = None;
+            self.source_venue = Some(update.venue);
+        }
+        // Only the venue the row shows is recorded, so trend and divergence
+        // compare like with like
+        self.history.record(FundingSample {
+            at: chrono::Utc::now(),
+            venue: update.venue,
+            hourly_funding: hourly_fraction(update.venue, update.funding),
+        });
+
+        let funding_changed = update.funding != self.funding;
+        if self.has_data() && funding_changed {
+            self.funding_changed_at = Some(Instant::now());
+        }
+        self.update_with_exchange(
+            update.funding,
+            update.open_interest,
+            update.oracle_price,
+            update.exchange,
+        );
+        self.mark_price = update.mark_price;
+        self.funding_updated_at = Some(Instant::now());
+        if is_valid_price(update.oracle_price) {
+            self.price_updated_at = Some(Instant::now());
+        }
+        self.spot_price = update.spot_price;
+        self.last_trade_price = update.last_trade_price;
+        if update.daily_change_pct.is_some() {
+            self.daily_change_pct = update.daily_change_pct;
+        }
+        if update.day_range.is_some() {
+            self.day_range = update.day_range;
+        }
+        self.native_symbol = Some(update.native_symbol.clone());
+        self.quotes
+            .insert(update.venue, VenueQuote::from_update(update));
+        // A venue without a stamp clears the previous venue's
+        self.venue_timestamp_ms = update.venue_timestamp_ms;
+    }
+
+    /// Whether a venue ahead of `venue` in `VENUE_PRIORITY` has data.
+    fn has_preferred_quote(&self, venue: u8) -> bool {
+        let rank = |v: u8| {
+            VENUE_PRIORITY
+                .iter()
+                .position(|p| *p == v)
+                .unwrap_or(usize::MAX)
+        };
+        self.quotes
+            .values()
+            .any(|q| q.open_interest != 0.0 && rank(q.venue) < rank(venue))
+    }
+
+    fn update_ema(&mut self, funding: f64) {
+        self.funding_ema = Some(match self.funding_ema {
+            Some(ema) => ema + FUNDING_EMA_ALPHA * (funding - ema),
+            None => funding,
+        });
+    }
+
+    /// Funding in venue units, smoothed or raw.
+    pub fn funding_value(&self, smoothed: bool) -> f64 {
+        if smoothed {
+            self.funding_ema.unwrap_or(self.funding)
+        } else {
+            self.funding
+        }
+    }
+
+    /// Hourly funding fraction alerts are evaluated against.
+    pub fn alert_funding(&self) -> f64 {
+        self.hourly_funding_value(ALERTS_USE_SMOOTHED)
+    }
+
+    /// Whether the latest funding is implausible (beyond
+    /// `MAX_SANE_HOURLY_FUNDING` per hour, or not a number) and likely a
+    /// venue glitch rather than a real rate.
+    pub fn is_funding_suspect(&self) -> bool {
+        let hourly = self.hourly_funding();
+        !hourly.is_finite() || hourly.abs() > MAX_SANE_HOURLY_FUNDING
+    }
+
+    /// Each venue's hourly funding with its share of the coin's USD open
+    /// interest. Venues without a usable price carry no weight.
+    pub fn venue_weights(&self) -> Vec<(u8, f64, f64)> {
+        let oi_usd: Vec<(u8, f64, f64)> = self
+            .quotes
+            .values()
+            .filter(|q| is_valid_price(q.oracle_price) && q.open_interest > 0.0)
+            .map(|q| (q.venue, q.hourly_funding, q.open_interest * q.oracle_price))
+            .collect();
+        let total: f64 = oi_usd.iter().map(|(_, _, oi)| oi).sum();
+        if total <= 0.0 {
+            return Vec::new();
+        }
+        oi_usd
+            .into_iter()
+            .map(|(venue, funding, oi)| (venue, funding, oi / total))
+            .collect()
+    }
+
+    /// Number of venues that have quoted this coin.
+    pub fn venue_count(&self) -> usize {
+        self.quotes.len()
+    }
+
+    /// Hourly funding across venues weighted by each venue's open interest,
+    /// as a fraction. `None` until some venue has OI and a price.
+    pub fn blended_funding(&self) -> Option<f64> {
+        let weights = self.venue_weights();
+        if weights.is_empty() {
+            return None;
+        }
+        Some(
+            weights
+                .iter()
+                .map(|(_, funding, share)| funding * share)
+                .sum(),
+        )
+    }
+
+    /// Gap between the highest and lowest hourly funding across venues, as
+    /// a fraction. `None` until two venues have quoted plausible rates.
+    pub fn funding_spread(&self) -> Option<f64> {
+        let rates: Vec<f64> = self
+            .quotes
+            .values()
+            .map(|q| q.hourly_funding)
+            .filter(|f| f.is_finite() && f.abs() <= MAX_SANE_HOURLY_FUNDING)
+            .collect();
+        if rates.len() < 2 {
+            return None;
+        }
+        let high = rates.iter().copied().fold(f64::MIN, f64::max);
+        let low = rates.iter().copied().fold(f64::MAX, f64::min);
+        Some(high - low)
+    }
+
+    /// Predicted minus last realized hourly funding, from the first venue
+    /// reporting both. `None` when no venue exposes a realized rate.
+    pub fn funding_delta(&self) -> Option<f64> {
+        self.quotes.values().find_map(|quote| {
+            quote
+                .realized_hourly_funding
+                .map(|realized| quote.hourly_funding - realized)
+        })
+    }
+
+    /// Mark-to-oracle basis (`mark / oracle - 1`) as a fraction, when both
+    /// prices are known.
+    pub fn basis(&self) -> Option<f64> {
+        (is_valid_price(self.mark_price) && is_valid_price(self.oracle_price))
+            .then(|| self.mark_price / self.oracle_price - 1.0)
+    }
+
+    /// Perp premium over spot (`mark / spot - 1`) as a fraction. `None` when
+    /// the source venue has no separate spot price.
+    pub fn premium(&self) -> Option<f64> {
+        let spot = self.spot_price?;
+        (is_valid_price(self.mark_price) && is_valid_price(spot))
+            .then(|| self.mark_price / spot - 1.0)
+    }
+
+    /// Basis spread over `BASIS_PERIOD_HOURS` as an hourly fraction, so it
+    /// can be scaled to a funding round and compared with funding directly.
+    pub fn basis_hourly(&self) -> Option<f64> {
+        self.basis().map(|basis| basis / BASIS_PERIOD_HOURS)
+    }
+
+    /// Open interest in USD, or `None` until a usable price has arrived.
+    pub fn open_interest_usd(&self) -> Option<f64> {
+        is_valid_price(self.oracle_price).then_some(self.open_interest * self.oracle_price)
+    }
+
+    /// Seconds since the source venue's funding stamp, i.e. the age of the
+    /// current funding round. `None` on venues that send no stamp.
+    pub fn venue_age_secs(&self) -> Option<f64> {
+        self.venue_timestamp_ms.map(|ts| {
+            let now = chrono::Utc::now().timestamp_millis();
+            (now - ts).max(0) as f64 / 1000.0
+        })
+    }
+
+    /// Progress through the source venue's funding interval and seconds to
+    /// settlement, once a venue has reported.
+    pub fn funding_countdown(&self) -> Option<(f64, i64)> {
+        let venue = self.source_venue?;
+        let now = chrono::Utc::now().timestamp_millis();
+        Some(funding_countdown(venue, self.venue_timestamp_ms, now))
+    }
+
+    /// Hourly funding as a fraction. Hyperliquid reports a fraction while
+    /// Lighter already reports a percentage.
+    pub fn hourly_funding(&self) -> f64 {
+        self.to_hourly_fraction(self.funding)
+    }
+
+    pub fn hourly_funding_value(&self, smoothed: bool) -> f64 {
+        self.to_hourly_fraction(self.funding_value(smoothed))
+    }
+
+    fn to_hourly_fraction(&self, funding: f64) -> f64 {
+        if let Some(venue) = self.source_venue {
+            return hourly_fraction(venue, funding);
+        }
+        if self.current_exchange & 1 == 1 {
+            funding
+        } else {
+            funding / 100.0
+        }
+    }
+
+    /// Whether the last usable price is older than `PRICE_STALE_SECS`, so
+    /// USD values derived from it may have drifted.
+    pub fn is_price_stale(&self) -> bool {
+        self.price_updated_at
+            .is_some_and(|at| at.elapsed() > Duration::from_secs(PRICE_STALE_SECS))
+    }
+
+    /// Whether every venue this coin has values from is currently down, as
+    /// judged by `connected`. The values are kept through a reconnect and
+    /// only marked stale until the next snapshot replaces them.
+    pub fn is_stale(&self, connected: impl Fn(u8) -> bool) -> bool {
+        self.has_data() && !self.quotes.keys().any(|venue| connected(*venue))
+    }
+
     pub fn has_data(&self) -> bool {
         self.open_interest != 0.0
     }
@@ -53,3 +348,124 @@ impl CoinData {
         self.current_exchange == 3
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::data::{HYPERLIQUID, LIGHTER};
+    use crate::health::FeedHealth;
+
+    fn coin_at(price: f64) -> CoinData {
+        let mut coin = CoinData::new("BTC".to_string());
+        coin.apply(&CoinUpdate::new(
+            "BTC".to_string(),
+            0.0001,
+            10.0,
+            price,
+            1,
+            HYPERLIQUID,
+        ));
+        coin
+    }
+
+    #[test]
+    fn usd_open_interest_needs_a_usable_price() {
+        assert_eq!(coin_at(50_000.0).open_interest_usd(), Some(500_000.0));
+        for price in [0.0, -1.0, f64::NAN, f64::INFINITY] {
+            assert_eq!(coin_at(price).open_interest_usd(), None, "price {}", price);
+        }
+    }
+
+    #[test]
+    fn values_persist_through_a_reconnect_and_refresh_after() {
+        let health = FeedHealth::new();
+        health.set_connected(HYPERLIQUID, true);
+        let mut coin = coin_at(50_000.0);
+        let connected = |venue| health.venue(venue).connected;
+        assert!(!coin.is_stale(connected));
+
+        // Disconnected: no updates arrive, the row keeps its values and is
+        // only marked stale
+        health.set_connected(HYPERLIQUID, false);
+        assert!(coin.is_stale(|venue| health.venue(venue).connected));
+        assert!(coin.has_data());
+        assert_eq!(coin.funding, 0.0001);
+        assert_eq!(coin.open_interest_usd(), Some(500_000.0));
+
+        // Reconnected: the first snapshot replaces the values in place
+        health.set_connected(HYPERLIQUID, true);
+        coin.apply(&CoinUpdate::new(
+            "BTC".to_string(),
+            0.0002,
+            12.0,
+            51_000.0,
+            1,
+            HYPERLIQUID,
+        ));
+        assert!(!coin.is_stale(|venue| health.venue(venue).connected));
+        assert_eq!(coin.funding, 0.0002);
+        assert_eq!(coin.open_interest_usd(), Some(612_000.0));
+        assert!(coin.funding_changed_at.is_some());
+    }
+
+    #[test]
+    fn zero_price_gives_no_basis_or_premium() {
+        let coin = coin_at(0.0);
+        assert_eq!(coin.basis(), None);
+        assert_eq!(coin.basis_hourly(), None);
+        assert_eq!(coin.premium(), None);
+        assert!(coin.price_updated_at.is_none());
+    }
+
+    #[test]
+    fn premium_needs_a_usable_spot_price() {
+        let premium_at = |spot: f64| {
+            let mut coin = CoinData::new("BTC".to_string());
+            coin.apply(
+                &CoinUpdate::new("BTC".to_string(), 0.0001, 10.0, 100.0, 1, HYPERLIQUID)
+                    .with_mark_price(101.0)
+                    .with_spot_price(spot),
+            );
+            coin.premium()
+        };
+        assert!((premium_at(100.0).unwrap() - 0.01).abs() < 1e-12);
+        for spot in [0.0, -1.0, f64::NAN, f64::INFINITY] {
+            assert_eq!(premium_at(spot), None, "spot {}", spot);
+        }
+    }
+
+    #[test]
+    fn combined_mode_records_only_the_shown_venue() {
+        let mut coin = CoinData::new("BTC".to_string());
+        for (funding, venue) in [
+            (0.0001, HYPERLIQUID),
+            (0.02, LIGHTER),
+            (0.0002, HYPERLIQUID),
+        ] {
+            coin.apply(&CoinUpdate::new(
+                "BTC".to_string(),
+                funding,
+                10.0,
+                50_000.0,
+                3,
+                venue,
+            ));
+        }
+        assert_eq!(coin.source_venue, Some(HYPERLIQUID));
+        assert!(coin.history.newest_first().all(|s| s.venue == HYPERLIQUID));
+    }
+
+    #[test]
+    fn spread_needs_two_venues() {
+        let mut coin = CoinData::new("BTC".to_string());
+        let quote =
+            |funding, venue| CoinUpdate::new("BTC".to_string(), funding, 10.0, 50_000.0, 3, venue);
+        coin.apply(&quote(0.0001, HYPERLIQUID));
+        assert_eq!(coin.funding_spread(), None);
+
+        coin.apply(&quote(0.02, LIGHTER));
+        let rates: Vec<f64> = coin.quotes.values().map(|q| q.hourly_funding).collect();
+        let spread = coin.funding_spread().unwrap();
+        assert!((spread - (rates[0] - rates[1]).abs()).abs() < 1e-15);
+    }
+}
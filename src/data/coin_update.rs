@@ -0,0 +1,235 @@
+use serde_json::json;
+
+use crate::data::{canonical_symbol, venue_short_name};
+
+/// A single market data update sent from a venue feed to the UI.
+#[derive(Clone, Debug)]
+pub struct CoinUpdate {
+    /// Canonical symbol shared across venues.
+    pub coin: String,
+    /// Symbol as the venue names it.
+    pub native_symbol: String,
+    pub funding: f64,
+    pub open_interest: f64,
+    pub oracle_price: f64,
+    /// Mark price, `0.0` when the venue doesn't report one.
+    pub mark_price: f64,
+    /// Spot price the perp trades against, or the venue's index price as a
+    /// stand-in. `None` when the venue reports neither.
+    pub spot_price: Option<f64>,
+    /// Price of the venue's last trade, where the feed reports it.
+    pub last_trade_price: Option<f64>,
+    /// Last settled funding in venue units, for venues that report it next
+    /// to the predicted rate in `funding`.
+    pub realized_funding: Option<f64>,
+    /// Price change over the last 24 hours, in percent.
+    pub daily_change_pct: Option<f64>,
+    /// Lowest and highest price over the last 24 hours.
+    pub day_range: Option<(f64, f64)>,
+    /// Exchange mode the feed runs under (1, 2, or 3 for combined).
+    pub exchange: u8,
+    /// Venue that produced this update (`HYPERLIQUID` or `LIGHTER`).
+    pub venue: u8,
+    /// Venue-provided timestamp in milliseconds, when the feed carries one.
+    pub venue_timestamp_ms: Option<i64>,
+    /// Feed generation that sent the update. The websocket manager starts a
+    /// new generation with every feed restart, so updates still draining
+    /// from a replaced feed can be told apart.
+    pub generation: u64,
+}
+
+impl CoinUpdate {
+    /// Builds an update from a venue-native symbol, normalizing it to the
+    /// canonical name.
+    pub fn new(
+        native_symbol: String,
+        funding: f64,
+        open_interest: f64,
+        oracle_price: f64,
+        exchange: u8,
+        venue: u8,
+    ) -> Self {
+        Self {
+            coin: canonical_symbol(&native_symbol),
+            native_symbol,
+            funding,
+            open_interest,
+            oracle_price,
+            mark_price: 0.0,
+            spot_price: None,
+            last_trade_price: None,
+            realized_funding: None,
+            daily_change_pct: None,
+            day_range: None,
+            exchange,
+            venue,
+            venue_timestamp_ms: None,
+            generation: 0,
+        }
+    }
+
+    pub fn with_mark_price(mut self, mark_price: f64) -> Self {
+        self.mark_price = mark_price;
+        self
+    }
+
+    pub fn with_spot_price(mut self, spot_price: f64) -> Self {
+        self.spot_price = Some(spot_price);
+        self
+    }
+
+    pub fn with_last_trade_price(mut self, last_trade_price: f64) -> Self {
+        self.last_trade_price = Some(last_trade_price);
+        self
+    }
+
+    pub fn with_realized_funding(mut self, realized_funding: f64) -> Self {
+        self.realized_funding = Some(realized_funding);
+        self
+    }
+
+    pub fn with_daily_change(mut self, change_pct: f64) -> Self {
+        self.daily_change_pct = Some(change_pct);
+        self
+    }
+
+    pub fn with_day_range(mut self, low: f64, high: f64) -> Self {
+        self.day_range = Some((low, high));
+        self
+    }
+
+    pub fn with_venue_timestamp(mut self, timestamp: i64) -> Self {
+        // Some venues report seconds rather than milliseconds
+        let timestamp_ms = if timestamp < 10_000_000_000 {
+            timestamp * 1000
+        } else {
+            timestamp
+        };
+        self.venue_timestamp_ms = Some(timestamp_ms);
+        self
+    }
+
+    /// Whether the update belongs to the feed currently shown: the selected
+    /// `exchange` at `generation` or later. Right after a switch the replaced
+    /// feed may still be draining updates for another exchange or an older
+    /// generation.
+    pub fn is_current(&self, exchange: u8, generation: u64) -> bool {
+        self.exchange == exchange && self.generation >= generation
+    }
+
+    pub fn to_json(&self) -> serde_json::Value {
+        json!({
+            "coin": self.coin,
+            "native_symbol": self.native_symbol,
+            "venue": venue_short_name(self.venue),
+            "funding": self.funding,
+            "open_interest": self.open_interest,
+            "oracle_price": self.oracle_price,
+            "mark_price": self.mark_price,
+            "spot_price": self.spot_price,
+            "last_trade_price": self.last_trade_price,
+            "realized_funding": self.realized_funding,
+            "daily_change_pct": self.daily_change_pct,
+            "venue_timestamp_ms": self.venue_timestamp_ms,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::data::{HYPERLIQUID, LIGHTER, OverflowPolicy, update_channel};
+
+    #[test]
+    fn updates_from_another_exchange_or_older_generation_are_stale() {
+        let update = |exchange, generation| CoinUpdate {
+            generation,
+            ..CoinUpdate::new("BTC".to_string(), 0.0001, 1.0, 1.0, exchange, HYPERLIQUID)
+        };
+        assert!(update(1, 2).is_current(1, 2));
+        assert!(update(1, 3).is_current(1, 2));
+        assert!(!update(1, 1).is_current(1, 2));
+        assert!(!update(3, 2).is_current(1, 2));
+    }
+
+    #[test]
+    fn updates_draining_from_a_replaced_feed_are_ignored_after_a_switch() {
+        let (tx, mut rx) = update_channel(16, OverflowPolicy::DropOldest);
+        let old_feed = tx.for_generation(1);
+        old_feed.try_send(CoinUpdate::new(
+            "BTC".to_string(),
+            0.0001,
+            1.0,
+            1.0,
+            1,
+            HYPERLIQUID,
+        ));
+
+        // Switch to Lighter: the manager starts generation 2, but the old
+        // Hyperliquid feed sends once more before it is aborted
+        let new_feed = tx.for_generation(2);
+        old_feed.try_send(CoinUpdate::new(
+            "ETH".to_string(),
+            0.0002,
+            1.0,
+            1.0,
+            1,
+            HYPERLIQUID,
+        ));
+        new_feed.try_send(CoinUpdate::new(
+            "SOL".to_string(),
+            0.003,
+            1.0,
+            1.0,
+            2,
+            LIGHTER,
+        ));
+        old_feed.try_send(CoinUpdate::new(
+            "DOGE".to_string(),
+            0.0004,
+            1.0,
+            1.0,
+            1,
+            HYPERLIQUID,
+        ));
+
+        let mut applied = Vec::new();
+        while let Some(update) = rx.try_recv() {
+            if update.is_current(2, 2) {
+                applied.push(update.coin);
+            }
+        }
+        assert_eq!(applied, ["SOL"]);
+    }
+
+    #[test]
+    fn a_restart_on_the_same_exchange_drops_the_old_generation() {
+        let (tx, mut rx) = update_channel(16, OverflowPolicy::DropOldest);
+        let old_feed = tx.for_generation(4);
+        let new_feed = tx.for_generation(5);
+        old_feed.try_send(CoinUpdate::new(
+            "BTC".to_string(),
+            0.0001,
+            1.0,
+            1.0,
+            3,
+            HYPERLIQUID,
+        ));
+        new_feed.try_send(CoinUpdate::new(
+            "BTC".to_string(),
+            0.0002,
+            1.0,
+            1.0,
+            3,
+            HYPERLIQUID,
+        ));
+
+        let mut applied = Vec::new();
+        while let Some(update) = rx.try_recv() {
+            if update.is_current(3, 5) {
+                applied.push(update.funding);
+            }
+        }
+        assert_eq!(applied, [0.0002]);
+    }
+}
@@ -0,0 +1,59 @@
+use crate::data::CoinData;
+
+/// When a coin's funding counts as diverging from its own recent average,
+/// configured in `DIVERGENCE_RULE`.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum DivergenceRule {
+    /// At least this many standard deviations from the average.
+    ZScore(f64),
+    /// At least this far from the average, as an hourly fraction.
+    Gap(f64),
+}
+
+/// How far a coin's current funding sits from the average of its recorded
+/// history on the same venue.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Divergence {
+    /// Average hourly funding over the history, as a fraction.
+    pub average: f64,
+    /// Current minus average hourly funding.
+    pub gap: f64,
+    /// Gap in standard deviations; `None` while the history is flat.
+    pub z_score: Option<f64>,
+    pub samples: usize,
+}
+
+impl Divergence {
+    /// Compares current funding with the source venue's samples in the
+    /// coin's history. `None` while funding is suspect or fewer than
+    /// `min_samples` have been recorded.
+    pub fn for_coin(coin: &CoinData, min_samples: usize) -> Option<Self> {
+        if coin.is_funding_suspect() {
+            return None;
+        }
+        let (average, std_dev, samples) = coin.history.stats(coin.source_venue?, min_samples)?;
+        let gap = coin.hourly_funding() - average;
+        Some(Self {
+            average,
+            gap,
+            z_score: (std_dev > 0.0).then(|| gap / std_dev),
+            samples,
+        })
+    }
+
+    /// Size of the divergence in the rule's units, regardless of sign.
+    pub fn magnitude(&self, rule: DivergenceRule) -> f64 {
+        match rule {
+            DivergenceRule::ZScore(_) => self.z_score.map_or(0.0, f64::abs),
+            DivergenceRule::Gap(_) => self.gap.abs(),
+        }
+    }
+
+    pub fn is_significant(&self, rule: DivergenceRule) -> bool {
+        match rule {
+            DivergenceRule::ZScore(limit) | DivergenceRule::Gap(limit) => {
+                self.magnitude(rule) >= limit
+            }
+        }
+    }
+}
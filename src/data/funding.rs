@@ -0,0 +1,93 @@
+use serde::{Deserialize, Serialize};
+
+use crate::config::FUNDING_DECIMALS;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum FundingRateRound {
+    Hourly,
+    QuadriHourly,
+    OctaHourly,
+    Daily,
+    Monthly,
+    Annually,
+}
+
+impl FundingRateRound {
+    pub fn hours(&self) -> f64 {
+        match self {
+            FundingRateRound::Hourly => 1.0,
+            FundingRateRound::QuadriHourly => 4.0,
+            FundingRateRound::OctaHourly => 8.0,
+            FundingRateRound::Daily => 24.0,
+            FundingRateRound::Monthly => 24.0 * 30.0,
+            FundingRateRound::Annually => 24.0 * 365.0,
+        }
+    }
+
+    pub fn next(&self) -> Self {
+        match self {
+            FundingRateRound::Hourly => FundingRateRound::QuadriHourly,
+            FundingRateRound::QuadriHourly => FundingRateRound::OctaHourly,
+            FundingRateRound::OctaHourly => FundingRateRound::Daily,
+            FundingRateRound::Daily => FundingRateRound::Monthly,
+            FundingRateRound::Monthly => FundingRateRound::Annually,
+            FundingRateRound::Annually => FundingRateRound::Hourly,
+        }
+    }
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            FundingRateRound::Hourly => "Hourly",
+            FundingRateRound::QuadriHourly => "4-Hourly",
+            FundingRateRound::OctaHourly => "8-Hourly",
+            FundingRateRound::Daily => "Daily",
+            FundingRateRound::Monthly => "Monthly",
+            FundingRateRound::Annually => "Annually",
+        }
+    }
+}
+
+/// Decimals to show for a funding percentage over `round`, from
+/// `FUNDING_DECIMALS`.
+pub fn precision_for(round: FundingRateRound) -> usize {
+    FUNDING_DECIMALS
+        .iter()
+        .find(|(r, _)| *r == round)
+        .map_or(6, |(_, decimals)| *decimals)
+}
+
+/// Scales an hourly funding rate (as a fraction) to the given period.
+///
+/// With `compound` set, hourly payments are reinvested, giving
+/// `(1 + hourly)^hours - 1` instead of `hourly * hours`. The compounded value
+/// saturates at `f64::MAX` for rates too large to represent.
+pub fn annualize(hourly: f64, round: FundingRateRound, compound: bool) -> f64 {
+    let hours = round.hours();
+    if !compound {
+        return hourly * hours;
+    }
+    if hourly <= -1.0 {
+        return -1.0;
+    }
+
+    let compounded = (hours * hourly.ln_1p()).exp_m1();
+    if compounded.is_finite() {
+        compounded
+    } else {
+        f64::MAX
+    }
+}
+
+/// Inverse of [`annualize`]: converts a rate over `round` back to the hourly
+/// fraction that produces it.
+pub fn deannualize(value: f64, round: FundingRateRound, compound: bool) -> f64 {
+    let hours = round.hours();
+    if !compound {
+        return value / hours;
+    }
+    if value <= -1.0 {
+        return -1.0;
+    }
+
+    (value.ln_1p() / hours).exp_m1()
+}
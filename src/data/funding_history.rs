@@ -0,0 +1,208 @@
+use std::collections::VecDeque;
+use std::time::Duration;
+
+use chrono::{DateTime, Utc};
+
+/// One recorded funding value.
+#[derive(Clone, Copy, Debug)]
+pub struct FundingSample {
+    pub at: DateTime<Utc>,
+    pub venue: u8,
+    /// Hourly funding as a fraction.
+    pub hourly_funding: f64,
+}
+
+/// Direction of funding over a lookback.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Trend {
+    Rising,
+    Falling,
+    Flat,
+}
+
+impl Trend {
+    pub fn arrow(&self) -> &'static str {
+        match self {
+            Trend::Rising => "↑",
+            Trend::Falling => "↓",
+            Trend::Flat => "→",
+        }
+    }
+}
+
+/// Shortest bucket a downsampled history uses; a zero interval would
+/// otherwise leave no room for samples.
+const MIN_INTERVAL: Duration = Duration::from_secs(1);
+
+/// Ring buffer of a coin's most recent funding samples, downsampled to at
+/// most one sample per venue per fixed time bucket.
+#[derive(Clone, Debug)]
+pub struct FundingHistory {
+    samples: VecDeque<FundingSample>,
+    capacity: usize,
+    interval_ms: i64,
+}
+
+impl FundingHistory {
+    /// A history covering `window`, holding the last sample of each
+    /// `interval`-long bucket. Intervals under a second are raised to one.
+    pub fn downsampled(interval: Duration, window: Duration) -> Self {
+        let interval = interval.max(MIN_INTERVAL);
+        let capacity = (window.as_millis() / interval.as_millis()).max(1) as usize;
+        Self {
+            samples: VecDeque::with_capacity(capacity),
+            capacity,
+            interval_ms: interval.as_millis() as i64,
+        }
+    }
+
+    /// Records a sample, replacing the newest one when both come from the
+    /// same venue and fall in the same bucket. Buckets are aligned to the
+    /// epoch so the axis stays regular.
+    pub fn record(&mut self, sample: FundingSample) {
+        let bucket = |s: &FundingSample| s.at.timestamp_millis().div_euclid(self.interval_ms);
+        if let Some(last) = self.samples.back_mut()
+            && last.venue == sample.venue
+            && bucket(last) == bucket(&sample)
+        {
+            *last = sample;
+            return;
+        }
+        self.push(sample);
+    }
+
+    fn push(&mut self, sample: FundingSample) {
+        if self.samples.len() == self.capacity {
+            self.samples.pop_front();
+        }
+        self.samples.push_back(sample);
+    }
+
+    pub fn len(&self) -> usize {
+        self.samples.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.samples.is_empty()
+    }
+
+    /// Compares `venue`'s newest sample with its last one at least
+    /// `lookback` older. Changes within `dead_band` (an hourly fraction)
+    /// count as flat, so noise doesn't flip the arrow. `None` until the
+    /// venue's samples span the lookback.
+    pub fn trend(&self, venue: u8, lookback: Duration, dead_band: f64) -> Option<Trend> {
+        let mut samples = self.samples.iter().rev().filter(|s| s.venue == venue);
+        let newest = samples.next()?;
+        let cutoff = newest.at - chrono::Duration::from_std(lookback).ok()?;
+        let past = samples.find(|s| s.at <= cutoff)?;
+        let change = newest.hourly_funding - past.hourly_funding;
+        Some(if change > dead_band {
+            Trend::Rising
+        } else if change < -dead_band {
+            Trend::Falling
+        } else {
+            Trend::Flat
+        })
+    }
+
+    /// Mean and standard deviation of the samples from `venue`, or `None`
+    /// with fewer than `min_samples` of them.
+    pub fn stats(&self, venue: u8, min_samples: usize) -> Option<(f64, f64, usize)> {
+        let values: Vec<f64> = self
+            .samples
+            .iter()
+            .filter(|s| s.venue == venue)
+            .map(|s| s.hourly_funding)
+            .collect();
+        if values.is_empty() || values.len() < min_samples {
+            return None;
+        }
+        let count = values.len() as f64;
+        let mean = values.iter().sum::<f64>() / count;
+        let variance = values.iter().map(|v| (v - mean).powi(2)).sum::<f64>() / count;
+        Some((mean, variance.sqrt(), values.len()))
+    }
+
+    /// Samples from newest to oldest.
+    pub fn newest_first(&self) -> impl Iterator<Item = &FundingSample> {
+        self.samples.iter().rev()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+
+    const VENUE: u8 = 1;
+    const OTHER_VENUE: u8 = 2;
+
+    fn sample(secs: i64, venue: u8, hourly_funding: f64) -> FundingSample {
+        FundingSample {
+            at: Utc.timestamp_opt(secs, 0).unwrap(),
+            venue,
+            hourly_funding,
+        }
+    }
+
+    fn history(interval_secs: u64, window_secs: u64) -> FundingHistory {
+        FundingHistory::downsampled(
+            Duration::from_secs(interval_secs),
+            Duration::from_secs(window_secs),
+        )
+    }
+
+    fn values(history: &FundingHistory) -> Vec<f64> {
+        history.newest_first().map(|s| s.hourly_funding).collect()
+    }
+
+    #[test]
+    fn same_bucket_keeps_the_last_sample() {
+        let mut history = history(5, 60);
+        history.record(sample(100, VENUE, 0.1));
+        history.record(sample(104, VENUE, 0.2));
+        history.record(sample(105, VENUE, 0.3));
+        assert_eq!(values(&history), [0.3, 0.2]);
+    }
+
+    #[test]
+    fn another_venue_in_the_same_bucket_does_not_replace_the_sample() {
+        let mut history = history(5, 60);
+        history.record(sample(100, VENUE, 0.1));
+        history.record(sample(101, OTHER_VENUE, 0.5));
+        assert_eq!(values(&history), [0.5, 0.1]);
+        assert_eq!(history.stats(VENUE, 1), Some((0.1, 0.0, 1)));
+    }
+
+    #[test]
+    fn oldest_sample_is_evicted_at_capacity() {
+        let mut history = history(5, 15);
+        for (i, value) in [0.1, 0.2, 0.3, 0.4].into_iter().enumerate() {
+            history.record(sample(i as i64 * 5, VENUE, value));
+        }
+        assert_eq!(history.len(), 3);
+        assert_eq!(values(&history), [0.4, 0.3, 0.2]);
+    }
+
+    #[test]
+    fn trend_ignores_other_venues() {
+        let mut history = history(5, 600);
+        history.record(sample(0, VENUE, 0.001));
+        history.record(sample(60, OTHER_VENUE, 0.5));
+        history.record(sample(120, VENUE, 0.001));
+        let lookback = Duration::from_secs(60);
+        assert_eq!(history.trend(VENUE, lookback, 0.0001), Some(Trend::Flat));
+
+        history.record(sample(180, VENUE, 0.003));
+        assert_eq!(history.trend(VENUE, lookback, 0.0001), Some(Trend::Rising));
+        assert_eq!(history.trend(OTHER_VENUE, lookback, 0.0001), None);
+    }
+
+    #[test]
+    fn zero_interval_still_keeps_history() {
+        let mut history = history(0, 10);
+        history.record(sample(100, VENUE, 0.1));
+        history.record(sample(101, VENUE, 0.2));
+        assert_eq!(values(&history), [0.2, 0.1]);
+    }
+}
@@ -0,0 +1,57 @@
+use crate::data::CoinData;
+
+/// Aggregate funding across coins, as hourly fractions.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct MarketIndex {
+    /// Mean funding weighted by open interest in USD, so majors count for
+    /// more than illiquid alts. `None` when no coin has a usable price.
+    pub weighted: Option<f64>,
+    /// Plain median funding, for comparison.
+    pub median: f64,
+    pub coins: usize,
+}
+
+impl MarketIndex {
+    /// Computes the index over coins with data, skipping suspect funding.
+    /// `None` when there is nothing to aggregate.
+    pub fn from_coins<'a>(
+        coins: impl IntoIterator<Item = &'a CoinData>,
+        smoothed: bool,
+    ) -> Option<Self> {
+        let mut fundings = Vec::new();
+        let mut weighted_sum = 0.0;
+        let mut total_oi = 0.0;
+
+        for coin in coins {
+            if !coin.has_data() || coin.is_funding_suspect() {
+                continue;
+            }
+            let funding = coin.hourly_funding_value(smoothed);
+            if !funding.is_finite() {
+                continue;
+            }
+            fundings.push(funding);
+            if let Some(oi) = coin.open_interest_usd().filter(|oi| *oi > 0.0) {
+                weighted_sum += funding * oi;
+                total_oi += oi;
+            }
+        }
+
+        if fundings.is_empty() {
+            return None;
+        }
+        fundings.sort_by(f64::total_cmp);
+        let mid = fundings.len() / 2;
+        let median = if fundings.len() % 2 == 0 {
+            (fundings[mid - 1] + fundings[mid]) / 2.0
+        } else {
+            fundings[mid]
+        };
+
+        Some(Self {
+            weighted: (total_oi > 0.0).then(|| weighted_sum / total_oi),
+            median,
+            coins: fundings.len(),
+        })
+    }
+}
@@ -1,3 +1,28 @@
+pub mod carry;
 pub mod coin_data;
+pub mod coin_update;
+pub mod divergence;
+pub mod funding;
+pub mod funding_history;
+pub mod market_index;
+pub mod position;
+pub mod symbol;
+pub mod update_channel;
+pub mod venue;
+pub mod venue_quote;
 
+pub use carry::{CarryInputs, CarryWeights};
 pub use coin_data::CoinData;
+pub use coin_update::CoinUpdate;
+pub use divergence::{Divergence, DivergenceRule};
+pub use funding::{FundingRateRound, annualize, deannualize, precision_for};
+pub use funding_history::{FundingHistory, FundingSample, Trend};
+pub use market_index::MarketIndex;
+pub use position::{Position, Side, position_for};
+pub use symbol::{canonical_symbol, display_symbol, is_pegged, search_key, trade_url};
+pub use update_channel::{OverflowPolicy, UpdateReceiver, UpdateSender, update_channel};
+pub use venue::{
+    FundingConvention, HYPERLIQUID, LIGHTER, VENUES, funding_convention, funding_countdown,
+    hourly_fraction, is_valid_price, venue_name, venue_short_name,
+};
+pub use venue_quote::VenueQuote;
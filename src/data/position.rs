@@ -0,0 +1,65 @@
+use crate::data::{CoinData, is_valid_price};
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Side {
+    Long,
+    Short,
+}
+
+/// A position held by the user, configured in `POSITIONS`.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Position {
+    /// Canonical coin symbol, e.g. `BTC`.
+    pub coin: &'static str,
+    pub side: Side,
+    /// Size in coin units, if known.
+    pub size: Option<f64>,
+}
+
+impl Position {
+    /// The funding rate from the holder's side: positive when paying,
+    /// negative when receiving. Longs pay positive funding, shorts receive it.
+    pub fn rate_paid(&self, hourly_funding: f64) -> f64 {
+        match self.side {
+            Side::Long => hourly_funding,
+            Side::Short => -hourly_funding,
+        }
+    }
+
+    /// Estimated hourly funding paid in USD on the position's notional at
+    /// the oracle price, negative for income. `None` without a size or price.
+    pub fn hourly_cost_usd(&self, coin: &CoinData, hourly_funding: f64) -> Option<f64> {
+        let size = self.size?;
+        is_valid_price(coin.oracle_price)
+            .then(|| size * coin.oracle_price * self.rate_paid(hourly_funding))
+    }
+}
+
+/// Finds the configured position for a coin.
+pub fn position_for(positions: &[Position], coin: &str) -> Option<Position> {
+    positions.iter().find(|p| p.coin == coin).copied()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn coin_at(price: f64) -> CoinData {
+        let mut coin = CoinData::new("BTC".to_string());
+        coin.oracle_price = price;
+        coin
+    }
+
+    #[test]
+    fn notional_cost_needs_a_usable_price() {
+        let position = Position {
+            coin: "BTC",
+            side: Side::Long,
+            size: Some(2.0),
+        };
+        assert_eq!(position.hourly_cost_usd(&coin_at(100.0), 0.001), Some(0.2));
+        for price in [0.0, -1.0, f64::NAN, f64::INFINITY] {
+            assert_eq!(position.hourly_cost_usd(&coin_at(price), 0.001), None);
+        }
+    }
+}
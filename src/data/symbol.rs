@@ -0,0 +1,108 @@
+use percent_encoding::{AsciiSet, NON_ALPHANUMERIC, utf8_percent_encode};
+
+use crate::config::{PEGGED_COINS, SYMBOL_ALIASES, SYMBOL_QUOTE_SUFFIXES};
+
+// Everything but RFC 3986 unreserved characters, so a symbol stays within
+// one path segment
+const PATH_SEGMENT: &AsciiSet = &NON_ALPHANUMERIC
+    .remove(b'-')
+    .remove(b'_')
+    .remove(b'.')
+    .remove(b'~');
+
+/// Whether a canonical symbol is listed in `PEGGED_COINS`.
+pub fn is_pegged(coin: &str) -> bool {
+    PEGGED_COINS.iter().any(|p| p.eq_ignore_ascii_case(coin))
+}
+
+/// Maps a venue-native symbol to the canonical name shared across venues.
+///
+/// Explicit aliases win; otherwise a trailing quote/contract suffix such as
+/// `USDT` or `-PERP` is stripped. Symbols already canonical pass through.
+pub fn canonical_symbol(native: &str) -> String {
+    if let Some((_, canonical)) = SYMBOL_ALIASES
+        .iter()
+        .find(|(alias, _)| alias.eq_ignore_ascii_case(native))
+    {
+        return canonical.to_string();
+    }
+
+    for suffix in SYMBOL_QUOTE_SUFFIXES {
+        if let Some(base) = native.strip_suffix(suffix)
+            && !base.is_empty()
+        {
+            return canonical_symbol(base);
+        }
+    }
+
+    native.to_string()
+}
+
+/// Folds a symbol or search query for matching: separators such as `/`, `-`
+/// or spaces are dropped and letters lowercased, so `btc/usd`, `BTC-USD` and
+/// `BTCUSD` all compare equal.
+pub fn search_key(symbol: &str) -> String {
+    symbol
+        .chars()
+        .filter(|c| c.is_alphanumeric())
+        .flat_map(char::to_lowercase)
+        .collect()
+}
+
+/// A symbol as shown in a cell, without surrounding whitespace or control
+/// characters that would break the row layout.
+pub fn display_symbol(symbol: &str) -> String {
+    symbol.trim().chars().filter(|c| !c.is_control()).collect()
+}
+
+/// Fills `{symbol}` in a trade URL template, percent-encoding the symbol so
+/// characters such as `/` or spaces don't change the URL's path.
+pub fn trade_url(template: &str, symbol: &str) -> String {
+    let encoded = utf8_percent_encode(symbol, PATH_SEGMENT).to_string();
+    template.replace("{symbol}", &encoded)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn trade_url_encodes_the_symbol() {
+        let template = "https://example.com/trade/{symbol}";
+        assert_eq!(trade_url(template, "BTC"), "https://example.com/trade/BTC");
+        assert_eq!(
+            trade_url(template, "BTC/USD"),
+            "https://example.com/trade/BTC%2FUSD"
+        );
+        assert_eq!(
+            trade_url(template, "kPEPE-PERP"),
+            "https://example.com/trade/kPEPE-PERP"
+        );
+        assert_eq!(
+            trade_url(template, "FOO BAR?#"),
+            "https://example.com/trade/FOO%20BAR%3F%23"
+        );
+    }
+
+    #[test]
+    fn search_key_drops_separators_and_case() {
+        assert_eq!(search_key("BTC/USD"), "btcusd");
+        assert_eq!(search_key("btc-usd"), "btcusd");
+        assert_eq!(search_key("FOO,BAR"), "foobar");
+        assert_eq!(search_key(" kPEPE "), "kpepe");
+    }
+
+    #[test]
+    fn search_key_keeps_leading_digits() {
+        assert_eq!(search_key("1000PEPE"), "1000pepe");
+        assert_eq!(search_key("1000-BONK/USD"), "1000bonkusd");
+        assert!(search_key("1000PEPE").starts_with(&search_key("1000")));
+    }
+
+    #[test]
+    fn display_symbol_strips_layout_breaking_characters() {
+        assert_eq!(display_symbol(" BTC/USD\n"), "BTC/USD");
+        assert_eq!(display_symbol("FOO,\tBAR"), "FOO,BAR");
+        assert_eq!(display_symbol("1000PEPE"), "1000PEPE");
+    }
+}
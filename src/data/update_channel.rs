@@ -0,0 +1,146 @@
+use std::collections::VecDeque;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Arc, Mutex};
+
+use crate::data::CoinUpdate;
+
+/// Which update to discard when the feed channel is full.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum OverflowPolicy {
+    /// Keep the queued updates and drop the incoming one.
+    DropNewest,
+    /// Evict the oldest queued update to make room for the incoming one.
+    DropOldest,
+}
+
+struct Shared {
+    queue: Mutex<VecDeque<CoinUpdate>>,
+    capacity: usize,
+    policy: OverflowPolicy,
+    dropped: AtomicU64,
+}
+
+/// Sending half of the bounded feed channel. Never blocks; a full channel
+/// drops an update according to the overflow policy.
+#[derive(Clone)]
+pub struct UpdateSender {
+    shared: Arc<Shared>,
+    /// Stamped on every update sent, when set.
+    generation: Option<u64>,
+}
+
+/// Receiving half of the bounded feed channel, drained by the UI each frame.
+pub struct UpdateReceiver {
+    shared: Arc<Shared>,
+}
+
+/// Creates a bounded feed channel holding at most `capacity` updates.
+pub fn update_channel(capacity: usize, policy: OverflowPolicy) -> (UpdateSender, UpdateReceiver) {
+    let shared = Arc::new(Shared {
+        queue: Mutex::new(VecDeque::with_capacity(capacity)),
+        capacity: capacity.max(1),
+        policy,
+        dropped: AtomicU64::new(0),
+    });
+    (
+        UpdateSender {
+            shared: Arc::clone(&shared),
+            generation: None,
+        },
+        UpdateReceiver { shared },
+    )
+}
+
+impl UpdateSender {
+    /// A sender into the same channel that tags updates with `generation`.
+    pub fn for_generation(&self, generation: u64) -> Self {
+        Self {
+            shared: Arc::clone(&self.shared),
+            generation: Some(generation),
+        }
+    }
+
+    /// Queues an update, returning `false` when one had to be dropped.
+    pub fn try_send(&self, mut update: CoinUpdate) -> bool {
+        if let Some(generation) = self.generation {
+            update.generation = generation;
+        }
+        let mut queue = self.shared.queue.lock().unwrap();
+        if queue.len() < self.shared.capacity {
+            queue.push_back(update);
+            return true;
+        }
+
+        self.shared.dropped.fetch_add(1, Ordering::Relaxed);
+        if self.shared.policy == OverflowPolicy::DropOldest {
+            queue.pop_front();
+            queue.push_back(update);
+        }
+        false
+    }
+}
+
+impl UpdateReceiver {
+    pub fn try_recv(&mut self) -> Option<CoinUpdate> {
+        self.shared.queue.lock().unwrap().pop_front()
+    }
+
+    /// Updates discarded because the channel was full.
+    pub fn dropped(&self) -> u64 {
+        self.shared.dropped.load(Ordering::Relaxed)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::data::HYPERLIQUID;
+
+    fn update(coin: &str) -> CoinUpdate {
+        CoinUpdate::new(coin.to_string(), 0.0001, 1.0, 1.0, 1, HYPERLIQUID)
+    }
+
+    fn drain(rx: &mut UpdateReceiver) -> Vec<String> {
+        std::iter::from_fn(|| rx.try_recv())
+            .map(|u| u.coin)
+            .collect()
+    }
+
+    #[test]
+    fn drop_newest_keeps_the_queue_and_counts_the_drop() {
+        let (tx, mut rx) = update_channel(2, OverflowPolicy::DropNewest);
+        assert!(tx.try_send(update("BTC")));
+        assert!(tx.try_send(update("ETH")));
+        assert!(!tx.try_send(update("SOL")));
+        assert_eq!(rx.dropped(), 1);
+        assert_eq!(drain(&mut rx), ["BTC", "ETH"]);
+    }
+
+    #[test]
+    fn drop_oldest_evicts_the_front_and_counts_the_drop() {
+        let (tx, mut rx) = update_channel(2, OverflowPolicy::DropOldest);
+        assert!(tx.try_send(update("BTC")));
+        assert!(tx.try_send(update("ETH")));
+        assert!(!tx.try_send(update("SOL")));
+        assert!(!tx.try_send(update("DOGE")));
+        assert_eq!(rx.dropped(), 2);
+        assert_eq!(drain(&mut rx), ["SOL", "DOGE"]);
+    }
+
+    #[test]
+    fn draining_makes_room_again() {
+        let (tx, mut rx) = update_channel(1, OverflowPolicy::DropNewest);
+        assert!(tx.try_send(update("BTC")));
+        assert_eq!(drain(&mut rx), ["BTC"]);
+        assert!(tx.try_send(update("ETH")));
+        assert_eq!(rx.dropped(), 0);
+    }
+
+    #[test]
+    fn zero_capacity_holds_one_update() {
+        let (tx, mut rx) = update_channel(0, OverflowPolicy::DropNewest);
+        assert!(tx.try_send(update("BTC")));
+        assert!(!tx.try_send(update("ETH")));
+        assert_eq!(drain(&mut rx), ["BTC"]);
+    }
+}
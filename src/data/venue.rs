@@ -0,0 +1,88 @@
+pub const HYPERLIQUID: u8 = 1;
+pub const LIGHTER: u8 = 2;
+
+/// Every venue the app can stream from.
+pub const VENUES: [u8; 2] = [HYPERLIQUID, LIGHTER];
+
+pub fn venue_name(venue: u8) -> &'static str {
+    match venue {
+        HYPERLIQUID => "Hyperliquid",
+        LIGHTER => "Lighter",
+        _ => "Unknown",
+    }
+}
+
+pub fn venue_short_name(venue: u8) -> &'static str {
+    match venue {
+        HYPERLIQUID => "HL",
+        LIGHTER => "LT",
+        _ => "?",
+    }
+}
+
+/// Whether a reported price can be used for USD conversions. Feeds report
+/// `0.0` before the first price arrives or when parsing fails.
+pub fn is_valid_price(price: f64) -> bool {
+    price.is_finite() && price > 0.0
+}
+
+/// How a venue quotes funding natively.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct FundingConvention {
+    /// Hours covered by one quoted rate.
+    pub interval_hours: f64,
+    /// Whether the rate is a percentage rather than a fraction.
+    pub percent: bool,
+}
+
+impl FundingConvention {
+    /// e.g. `1h rate, as a percentage`.
+    pub fn describe(&self) -> String {
+        format!(
+            "{}h rate, as a {}",
+            self.interval_hours,
+            if self.percent {
+                "percentage"
+            } else {
+                "fraction"
+            }
+        )
+    }
+}
+
+pub fn funding_convention(venue: u8) -> FundingConvention {
+    match venue {
+        LIGHTER => FundingConvention {
+            interval_hours: 1.0,
+            percent: true,
+        },
+        _ => FundingConvention {
+            interval_hours: 1.0,
+            percent: false,
+        },
+    }
+}
+
+/// Converts a venue's raw funding value to an hourly fraction. Hyperliquid
+/// reports a fraction while Lighter reports a percentage.
+pub fn hourly_fraction(venue: u8, funding: f64) -> f64 {
+    let convention = funding_convention(venue);
+    let fraction = if convention.percent {
+        funding / 100.0
+    } else {
+        funding
+    };
+    fraction / convention.interval_hours
+}
+
+/// Progress through a venue's current funding interval in `[0, 1)` and the
+/// seconds left until it settles. Intervals line up with `anchor_ms`, a
+/// funding timestamp from the venue, or with UTC boundaries without one.
+pub fn funding_countdown(venue: u8, anchor_ms: Option<i64>, now_ms: i64) -> (f64, i64) {
+    let interval_ms = (funding_convention(venue).interval_hours * 3_600_000.0) as i64;
+    let elapsed_ms = (now_ms - anchor_ms.unwrap_or(0)).rem_euclid(interval_ms);
+    (
+        elapsed_ms as f64 / interval_ms as f64,
+        (interval_ms - elapsed_ms) / 1000,
+    )
+}
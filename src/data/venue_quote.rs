@@ -0,0 +1,35 @@
+use std::time::Instant;
+
+use crate::data::{CoinUpdate, hourly_fraction};
+
+/// Latest values one venue reported for a coin.
+#[derive(Clone, Debug)]
+pub struct VenueQuote {
+    pub venue: u8,
+    /// Hourly funding as a fraction, comparable across venues.
+    pub hourly_funding: f64,
+    /// Last settled hourly funding as a fraction, when the venue reports it.
+    pub realized_hourly_funding: Option<f64>,
+    pub open_interest: f64,
+    pub oracle_price: f64,
+    pub mark_price: f64,
+    pub spot_price: Option<f64>,
+    pub updated_at: Instant,
+}
+
+impl VenueQuote {
+    pub fn from_update(update: &CoinUpdate) -> Self {
+        Self {
+            venue: update.venue,
+            hourly_funding: hourly_fraction(update.venue, update.funding),
+            realized_hourly_funding: update
+                .realized_funding
+                .map(|f| hourly_fraction(update.venue, f)),
+            open_interest: update.open_interest,
+            oracle_price: update.oracle_price,
+            mark_price: update.mark_price,
+            spot_price: update.spot_price,
+            updated_at: Instant::now(),
+        }
+    }
+}
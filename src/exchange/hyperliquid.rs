@@ -0,0 +1,62 @@
+use color_eyre::Result;
+use futures::FutureExt;
+use futures::future::BoxFuture;
+use std::sync::Arc;
+use std::time::Duration;
+
+use crate::data::{HYPERLIQUID, UpdateSender};
+use crate::exchange::Exchange;
+use crate::health::FeedHealth;
+use crate::paths::write_debug_log;
+use crate::request::{FetchError, perp_universe};
+use crate::websocket::{ConnectError, hyperliquid_websocket, probe_hyperliquid};
+
+fn log_debug(msg: String) {
+    write_debug_log("HYPERLIQUID", &msg);
+}
+
+pub struct Hyperliquid;
+
+impl Exchange for Hyperliquid {
+    fn venue(&self) -> u8 {
+        HYPERLIQUID
+    }
+
+    fn coin_list(&self) -> BoxFuture<'static, Result<Vec<String>, FetchError>> {
+        async {
+            // Entries that never receive perp asset contexts would leave
+            // rows empty forever, so only tradable perps are listed
+            let mut perps = Vec::new();
+            let mut skipped = Vec::new();
+            for asset in perp_universe().await? {
+                match asset.skip_reason() {
+                    None => perps.push(asset.name),
+                    Some(reason) => skipped.push(format!("{} ({})", asset.name, reason)),
+                }
+            }
+            if !skipped.is_empty() {
+                log_debug(format!(
+                    "Skipped {} non-perp universe entries: {}",
+                    skipped.len(),
+                    skipped.join(", ")
+                ));
+            }
+            Ok(perps)
+        }
+        .boxed()
+    }
+
+    fn run_feed(
+        &self,
+        coins: Vec<String>,
+        tx: UpdateSender,
+        exchange: u8,
+        health: Arc<FeedHealth>,
+    ) -> BoxFuture<'static, Result<()>> {
+        hyperliquid_websocket(coins, tx, exchange, health).boxed()
+    }
+
+    fn probe_stream(&self, limit: Duration) -> BoxFuture<'static, Result<(), ConnectError>> {
+        probe_hyperliquid(limit).boxed()
+    }
+}
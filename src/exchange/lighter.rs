@@ -0,0 +1,44 @@
+use color_eyre::Result;
+use futures::FutureExt;
+use futures::future::BoxFuture;
+use std::sync::Arc;
+use std::time::Duration;
+
+use crate::data::{LIGHTER, UpdateSender};
+use crate::exchange::Exchange;
+use crate::health::FeedHealth;
+use crate::request::{FetchError, coin_list_metadate_lighter};
+use crate::websocket::{ConnectError, lighter_websocket, probe_lighter};
+
+pub struct Lighter;
+
+impl Exchange for Lighter {
+    fn venue(&self) -> u8 {
+        LIGHTER
+    }
+
+    fn coin_list(&self) -> BoxFuture<'static, Result<Vec<String>, FetchError>> {
+        async {
+            let funding_rates = coin_list_metadate_lighter().await?;
+            Ok(funding_rates
+                .iter()
+                .map(|rate| rate.symbol.clone())
+                .collect())
+        }
+        .boxed()
+    }
+
+    fn run_feed(
+        &self,
+        coins: Vec<String>,
+        tx: UpdateSender,
+        exchange: u8,
+        health: Arc<FeedHealth>,
+    ) -> BoxFuture<'static, Result<()>> {
+        lighter_websocket(coins, tx, exchange, health).boxed()
+    }
+
+    fn probe_stream(&self, limit: Duration) -> BoxFuture<'static, Result<(), ConnectError>> {
+        probe_lighter(limit).boxed()
+    }
+}
@@ -0,0 +1,65 @@
+pub mod hyperliquid;
+pub mod lighter;
+
+use color_eyre::Result;
+use futures::future::BoxFuture;
+use std::sync::Arc;
+use std::time::Duration;
+
+use crate::data::{HYPERLIQUID, UpdateSender, venue_name};
+use crate::health::FeedHealth;
+use crate::request::FetchError;
+use crate::websocket::ConnectError;
+
+pub use hyperliquid::Hyperliquid;
+pub use lighter::Lighter;
+
+/// A venue the app can list coins for and stream updates from. Adding a
+/// venue means implementing this and adding it to `registry`.
+pub trait Exchange: Send + Sync {
+    /// Venue id, as in `crate::data::venue`.
+    fn venue(&self) -> u8;
+
+    fn name(&self) -> &'static str {
+        venue_name(self.venue())
+    }
+
+    /// Fetches the venue's tradable coins, in venue-native symbols.
+    fn coin_list(&self) -> BoxFuture<'static, Result<Vec<String>, FetchError>>;
+
+    /// Streams updates for `coins` into `tx` until the feed ends, tagging
+    /// them with the UI `exchange` id they were requested for.
+    fn run_feed(
+        &self,
+        coins: Vec<String>,
+        tx: UpdateSender,
+        exchange: u8,
+        health: Arc<FeedHealth>,
+    ) -> BoxFuture<'static, Result<()>>;
+
+    /// Opens the venue's stream once and closes it, giving up after `limit`.
+    fn probe_stream(&self, limit: Duration) -> BoxFuture<'static, Result<(), ConnectError>>;
+}
+
+/// Every supported venue, in display order.
+pub fn registry() -> Vec<Box<dyn Exchange>> {
+    vec![Box::new(Hyperliquid), Box::new(Lighter)]
+}
+
+/// Resolves a UI exchange id to the venues it streams from: a venue id
+/// selects that venue and 3 selects all of them. Unknown ids fall back to
+/// Hyperliquid. The first entry is the one whose coin list is shown.
+pub fn exchanges_for(exchange: u8) -> Vec<Box<dyn Exchange>> {
+    if exchange == 3 {
+        return registry();
+    }
+    let (selected, others): (Vec<_>, Vec<_>) =
+        registry().into_iter().partition(|e| e.venue() == exchange);
+    if !selected.is_empty() {
+        return selected;
+    }
+    others
+        .into_iter()
+        .filter(|e| e.venue() == HYPERLIQUID)
+        .collect()
+}
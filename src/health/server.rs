@@ -0,0 +1,57 @@
+use color_eyre::Result;
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::io::{AsyncReadExt, AsyncWriteExt};
+use tokio::net::{TcpListener, TcpStream};
+use tokio::sync::watch;
+
+use crate::config::HEALTH_MAX_UPDATE_AGE_SECS;
+use crate::health::FeedHealth;
+
+/// Serves `GET /health` on `addr`: 200 while a venue feed is live, 503
+/// otherwise. Any other request gets a 404. Closes the listener and returns
+/// once `shutdown` changes.
+pub async fn serve_health(
+    addr: &str,
+    health: Arc<FeedHealth>,
+    mut shutdown: watch::Receiver<bool>,
+) -> Result<()> {
+    let listener = TcpListener::bind(addr).await?;
+    loop {
+        let (stream, _) = tokio::select! {
+            accepted = listener.accept() => accepted?,
+            _ = shutdown.changed() => return Ok(()),
+        };
+        let health = Arc::clone(&health);
+        tokio::spawn(async move {
+            let _ = handle_connection(stream, &health).await;
+        });
+    }
+}
+
+async fn handle_connection(mut stream: TcpStream, health: &FeedHealth) -> Result<()> {
+    let mut buf = [0u8; 1024];
+    let n = stream.read(&mut buf).await?;
+    let request = String::from_utf8_lossy(&buf[..n]);
+    let request_line = request.lines().next().unwrap_or_default();
+
+    let (status, body) = if request_line.starts_with("GET /health ") {
+        if health.is_healthy(Duration::from_secs(HEALTH_MAX_UPDATE_AGE_SECS)) {
+            ("200 OK", "ok")
+        } else {
+            ("503 Service Unavailable", "unavailable")
+        }
+    } else {
+        ("404 Not Found", "not found")
+    };
+
+    let response = format!(
+        "HTTP/1.1 {}\r\nContent-Type: text/plain\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+        status,
+        body.len(),
+        body
+    );
+    stream.write_all(response.as_bytes()).await?;
+    stream.shutdown().await?;
+    Ok(())
+}
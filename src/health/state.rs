@@ -0,0 +1,64 @@
+use std::collections::HashMap;
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+
+#[derive(Clone, Copy, Debug, Default)]
+pub struct VenueHealth {
+    pub connected: bool,
+    pub last_update: Option<Instant>,
+    /// Subscriptions that failed on the current connection.
+    pub failed_subscriptions: usize,
+}
+
+/// Connection state and last update time per venue, keyed by exchange id
+/// (1 = Hyperliquid, 2 = Lighter).
+#[derive(Debug, Default)]
+pub struct FeedHealth {
+    venues: Mutex<HashMap<u8, VenueHealth>>,
+}
+
+impl FeedHealth {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn set_connected(&self, venue: u8, connected: bool) {
+        let mut venues = self.venues.lock().unwrap();
+        venues.entry(venue).or_default().connected = connected;
+    }
+
+    pub fn record_update(&self, venue: u8) {
+        let mut venues = self.venues.lock().unwrap();
+        venues.entry(venue).or_default().last_update = Some(Instant::now());
+    }
+
+    pub fn record_subscribe_failure(&self, venue: u8) {
+        let mut venues = self.venues.lock().unwrap();
+        venues.entry(venue).or_default().failed_subscriptions += 1;
+    }
+
+    /// Clears failure counts when a venue starts a fresh connection.
+    pub fn reset_subscribe_failures(&self, venue: u8) {
+        let mut venues = self.venues.lock().unwrap();
+        venues.entry(venue).or_default().failed_subscriptions = 0;
+    }
+
+    pub fn venue(&self, venue: u8) -> VenueHealth {
+        self.venues
+            .lock()
+            .unwrap()
+            .get(&venue)
+            .copied()
+            .unwrap_or_default()
+    }
+
+    /// True when at least one venue is connected and has produced an update
+    /// within `max_age`.
+    pub fn is_healthy(&self, max_age: Duration) -> bool {
+        self.venues
+            .lock()
+            .unwrap()
+            .values()
+            .any(|v| v.connected && v.last_update.is_some_and(|t| t.elapsed() <= max_age))
+    }
+}
@@ -3,21 +3,60 @@
 //! Live table of Coin | Funding Rate | Open Interest
 //! Updates via WebSocket subscriptions.
 
+pub mod alert;
 pub mod app;
 pub mod config;
 pub mod data;
+pub mod exchange;
+pub mod health;
+pub mod paths;
 pub mod request;
+pub mod snapshot;
 pub mod third_party;
+pub mod time;
 pub mod ui;
 pub mod websocket;
 
 use crate::app::App;
+use crate::app::check::run_check;
+use crate::app::terminal;
+use crate::config::HEADLESS_ALERTS;
+use crate::request::dump_universe;
 use color_eyre::Result;
+use color_eyre::eyre::eyre;
 
 #[tokio::main(flavor = "multi_thread", worker_threads = 10)]
 async fn main() -> Result<()> {
-    color_eyre::install()?;
+    terminal::install_hooks()?;
+
+    let args: Vec<String> = std::env::args().collect();
+    if let Some(i) = args.iter().position(|arg| arg == "--dump-universe") {
+        let exchange = match args.get(i + 1).map(String::as_str) {
+            Some("hyperliquid" | "hl") => 1,
+            Some("lighter" | "lt") => 2,
+            Some("both") => 3,
+            other => {
+                return Err(eyre!(
+                    "--dump-universe expects hyperliquid, lighter or both, got {:?}",
+                    other
+                ));
+            }
+        };
+        println!("{}", dump_universe(exchange).await?);
+        return Ok(());
+    }
+
+    if args.iter().any(|arg| arg == "--check") {
+        if !run_check().await {
+            std::process::exit(1);
+        }
+        return Ok(());
+    }
 
     let app = App::new();
-    app.run().await
+    if HEADLESS_ALERTS || args.iter().any(|arg| arg == "--headless-alerts") {
+        app.run_headless_alerts().await
+    } else {
+        app.run().await
+    }
 }
@@ -0,0 +1,45 @@
+use std::env;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::sync::OnceLock;
+
+use crate::config::{DATA_DIR, DATA_DIR_ENV};
+
+static RESOLVED_DATA_DIR: OnceLock<PathBuf> = OnceLock::new();
+
+/// Directory holding every file the app persists (logs, exports, state).
+///
+/// Resolved once, in order: `$HYPE_DATA_DIR`, the `DATA_DIR` config,
+/// `$XDG_DATA_HOME/hype`, `~/.local/share/hype`, and finally the system temp
+/// directory. The directory is created if it does not exist.
+pub fn data_dir() -> &'static Path {
+    RESOLVED_DATA_DIR.get_or_init(|| {
+        let dir = resolve();
+        let _ = fs::create_dir_all(&dir);
+        dir
+    })
+}
+
+/// Path of a file named `name` inside the data directory.
+pub fn data_file(name: &str) -> PathBuf {
+    data_dir().join(name)
+}
+
+fn resolve() -> PathBuf {
+    if let Some(dir) = env::var_os(DATA_DIR_ENV).filter(|d| !d.is_empty()) {
+        return PathBuf::from(dir);
+    }
+    if let Some(dir) = DATA_DIR {
+        return PathBuf::from(dir);
+    }
+    if let Some(dir) = env::var_os("XDG_DATA_HOME").filter(|d| !d.is_empty()) {
+        return PathBuf::from(dir).join("hype");
+    }
+    if let Some(home) = env::var_os("HOME").filter(|d| !d.is_empty()) {
+        return PathBuf::from(home)
+            .join(".local")
+            .join("share")
+            .join("hype");
+    }
+    env::temp_dir().join("hype")
+}
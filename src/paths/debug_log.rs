@@ -0,0 +1,25 @@
+use std::fs::OpenOptions;
+use std::io::Write;
+
+use crate::config::DEBUG_LOG_FILE;
+use crate::paths::data_file;
+use crate::time::Timezone;
+
+/// Appends a timestamped line to the debug log in the data directory,
+/// prefixed with `component` unless it is empty. Failures are ignored so
+/// logging never interrupts the caller.
+pub fn write_debug_log(component: &str, msg: &str) {
+    let Ok(mut file) = OpenOptions::new()
+        .create(true)
+        .append(true)
+        .open(data_file(DEBUG_LOG_FILE))
+    else {
+        return;
+    };
+    let time = Timezone::display().format_now("%H:%M:%S");
+    let _ = if component.is_empty() {
+        writeln!(file, "[{}] {}", time, msg)
+    } else {
+        writeln!(file, "[{}] {}: {}", time, component, msg)
+    };
+}
@@ -0,0 +1,7 @@
+pub mod data_dir;
+pub mod debug_log;
+pub mod state;
+
+pub use data_dir::{data_dir, data_file};
+pub use debug_log::write_debug_log;
+pub use state::{load_state, save_state};
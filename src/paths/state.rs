@@ -0,0 +1,17 @@
+use std::fs;
+use std::io;
+
+use crate::paths::data_file;
+
+/// Reads a small piece of saved state from the data directory, if present.
+pub fn load_state(name: &str) -> Option<String> {
+    fs::read_to_string(data_file(name))
+        .ok()
+        .map(|s| s.trim().to_string())
+}
+
+/// Saves a small piece of state to the data directory, replacing any
+/// previous value.
+pub fn save_state(name: &str, value: &str) -> io::Result<()> {
+    fs::write(data_file(name), value)
+}
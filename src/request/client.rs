@@ -1,23 +1,74 @@
+use crate::config::SHARE_INFO_CLIENT;
+use crate::request::FetchError;
+use crate::third_party::hyperliquid::api_path::{HYPERLIQUID_INFO_API, HYPERLIQUID_META_REQUEST};
+use crate::third_party::hyperliquid::data::{PerpMeta, UniverseAsset};
 use crate::third_party::lighter::{api_path::LIGHTER_FUNDING_RATE_API, data::*};
 use hyperliquid_rust_sdk::{BaseUrl, InfoClient, Meta};
 
 use reqwest::get;
+use reqwest::header::CONTENT_TYPE;
+use std::sync::LazyLock;
+use tokio::sync::OnceCell;
 
-pub async fn coin_list_metadata() -> anyhow::Result<Meta> {
-    let client = InfoClient::new(None, Some(BaseUrl::Mainnet))
-        .await
-        .expect("Failed to create client");
+/// REST client shared by metadata fetches. It never subscribes, so it holds
+/// no websocket and `meta` only needs a shared reference; feeds keep their
+/// own clients. A failed setup is not cached and is retried on next use.
+static SHARED_INFO_CLIENT: OnceCell<InfoClient> = OnceCell::const_new();
 
-    let info = client.meta().await.expect("Failed to get meta");
+/// HTTP client for raw info requests, shared so its connection pool is
+/// reused across exchange switches and combined-mode fetches.
+static SHARED_HTTP_CLIENT: LazyLock<reqwest::Client> = LazyLock::new(reqwest::Client::new);
+
+async fn shared_info_client() -> Result<&'static InfoClient, FetchError> {
+    let client = SHARED_INFO_CLIENT
+        .get_or_try_init(|| InfoClient::new(None, Some(BaseUrl::Mainnet)))
+        .await?;
+    Ok(client)
+}
+
+pub async fn coin_list_metadata() -> Result<Meta, FetchError> {
+    if SHARE_INFO_CLIENT {
+        return Ok(shared_info_client().await?.meta().await?);
+    }
+
+    let client = InfoClient::new(None, Some(BaseUrl::Mainnet)).await?;
+
+    let info = client.meta().await?;
 
     Ok(info)
 }
 
-pub async fn coin_list_metadate_lighter() -> anyhow::Result<Vec<FundingRate>> {
-    let response = get(LIGHTER_FUNDING_RATE_API).await?.text().await?;
+/// Hyperliquid's perp universe straight from the `meta` info endpoint, with
+/// the fields needed to tell tradable perps from other entries.
+pub async fn perp_universe() -> Result<Vec<UniverseAsset>, FetchError> {
+    let client = if SHARE_INFO_CLIENT {
+        SHARED_HTTP_CLIENT.clone()
+    } else {
+        reqwest::Client::new()
+    };
+    let response = client
+        .post(HYPERLIQUID_INFO_API)
+        .header(CONTENT_TYPE, "application/json")
+        .body(HYPERLIQUID_META_REQUEST)
+        .send()
+        .await?;
+    if !response.status().is_success() {
+        return Err(FetchError::BadStatus(response.status().as_u16()));
+    }
+    let response = response.text().await?;
+    let meta: PerpMeta = serde_json::from_str(&response)?;
+    Ok(meta.universe)
+}
+
+pub async fn coin_list_metadate_lighter() -> Result<Vec<FundingRate>, FetchError> {
+    let response = get(LIGHTER_FUNDING_RATE_API).await?;
+    if !response.status().is_success() {
+        return Err(FetchError::BadStatus(response.status().as_u16()));
+    }
+    let response = response.text().await?;
     let parse_json: ApiFundingRatesResponse = serde_json::from_str(&response)?;
     if parse_json.code != 200 {
-        return Err(anyhow::anyhow!("Failed to get funding rates"));
+        return Err(FetchError::BadStatus(parse_json.code));
     }
     let mut funding_rates = parse_json.funding_rates;
     funding_rates.dedup_by_key(|c| c.market_id);
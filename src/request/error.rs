@@ -0,0 +1,27 @@
+use thiserror::Error;
+
+/// Failures while fetching venue metadata over HTTP or the Hyperliquid SDK.
+#[derive(Debug, Error)]
+pub enum FetchError {
+    #[error("HTTP request failed: {0}")]
+    Http(#[from] reqwest::Error),
+    #[error("unexpected status {0}")]
+    BadStatus(u16),
+    #[error("failed to parse response: {0}")]
+    Parse(#[from] serde_json::Error),
+    #[error("Hyperliquid request failed: {0}")]
+    Hyperliquid(#[from] hyperliquid_rust_sdk::Error),
+}
+
+impl FetchError {
+    /// Whether the failure is likely transient (network trouble or a server
+    /// side error) and worth retrying, as opposed to a malformed response.
+    pub fn is_retryable(&self) -> bool {
+        match self {
+            FetchError::Http(e) => !e.is_decode(),
+            FetchError::BadStatus(status) => *status >= 500 || *status == 429,
+            FetchError::Parse(_) => false,
+            FetchError::Hyperliquid(_) => true,
+        }
+    }
+}
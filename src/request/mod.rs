@@ -1,3 +1,7 @@
 pub mod client;
+pub mod error;
+pub mod universe;
 
-pub use client::{coin_list_metadata, coin_list_metadate_lighter};
+pub use client::{coin_list_metadata, coin_list_metadate_lighter, perp_universe};
+pub use error::FetchError;
+pub use universe::dump_universe;
@@ -0,0 +1,38 @@
+use serde_json::{Value, json};
+
+use crate::data::{HYPERLIQUID, LIGHTER, venue_name};
+use crate::request::{FetchError, coin_list_metadata, coin_list_metadate_lighter};
+
+/// Fetches a venue's full coin list with its metadata: Hyperliquid asset
+/// names and size decimals, or Lighter market ids and symbols.
+async fn venue_universe(venue: u8) -> Result<Value, FetchError> {
+    let assets: Vec<Value> = if venue == HYPERLIQUID {
+        coin_list_metadata()
+            .await?
+            .universe
+            .iter()
+            .map(|asset| json!({ "name": asset.name, "sz_decimals": asset.sz_decimals }))
+            .collect()
+    } else {
+        coin_list_metadate_lighter()
+            .await?
+            .iter()
+            .map(|rate| json!({ "name": rate.symbol, "market_id": rate.market_id }))
+            .collect()
+    };
+    Ok(json!({ "venue": venue_name(venue), "assets": assets }))
+}
+
+/// Dumps the coin universe of an exchange (1 Hyperliquid, 2 Lighter, 3 both)
+/// as pretty-printed JSON, for bootstrapping symbol mappings elsewhere.
+pub async fn dump_universe(exchange: u8) -> Result<String, FetchError> {
+    let universe = match exchange {
+        1 => venue_universe(HYPERLIQUID).await?,
+        2 => venue_universe(LIGHTER).await?,
+        _ => json!([
+            venue_universe(HYPERLIQUID).await?,
+            venue_universe(LIGHTER).await?
+        ]),
+    };
+    Ok(serde_json::to_string_pretty(&universe)?)
+}
@@ -0,0 +1,5 @@
+pub mod server;
+pub mod state;
+
+pub use server::serve_snapshot;
+pub use state::SharedSnapshot;
@@ -0,0 +1,83 @@
+use color_eyre::Result;
+use std::path::Path;
+use std::sync::Arc;
+use tokio::sync::watch;
+
+use crate::snapshot::SharedSnapshot;
+
+/// Serves the snapshot on a Unix domain socket at `path`: each client gets
+/// the latest update per coin and venue as one JSON line, then, with
+/// `stream` set, every following update as a JSON line until it
+/// disconnects. Removes the socket file and returns once `shutdown` changes.
+#[cfg(unix)]
+pub async fn serve_snapshot(
+    path: &Path,
+    snapshot: Arc<SharedSnapshot>,
+    stream: bool,
+    mut shutdown: watch::Receiver<bool>,
+) -> Result<()> {
+    use tokio::net::UnixListener;
+
+    // A socket left behind by a previous run would make bind fail
+    if path.exists() {
+        std::fs::remove_file(path)?;
+    }
+    let listener = UnixListener::bind(path)?;
+    loop {
+        let (client, _) = tokio::select! {
+            accepted = listener.accept() => accepted?,
+            _ = shutdown.changed() => break,
+        };
+        let snapshot = Arc::clone(&snapshot);
+        tokio::spawn(async move {
+            // A client hanging up mid-write just ends its connection
+            let _ = handle_client(client, &snapshot, stream).await;
+        });
+    }
+    let _ = std::fs::remove_file(path);
+    Ok(())
+}
+
+#[cfg(not(unix))]
+pub async fn serve_snapshot(
+    _path: &Path,
+    _snapshot: Arc<SharedSnapshot>,
+    _stream: bool,
+    _shutdown: watch::Receiver<bool>,
+) -> Result<()> {
+    Err(color_eyre::eyre::eyre!(
+        "the snapshot socket needs Unix domain sockets"
+    ))
+}
+
+#[cfg(unix)]
+async fn handle_client(
+    mut client: tokio::net::UnixStream,
+    snapshot: &SharedSnapshot,
+    stream: bool,
+) -> Result<()> {
+    use tokio::io::AsyncWriteExt;
+    use tokio::sync::broadcast::error::RecvError;
+
+    // Subscribe before taking the snapshot so no update falls in between
+    let mut updates = snapshot.subscribe();
+    client
+        .write_all(format!("{}\n", snapshot.to_json()).as_bytes())
+        .await?;
+    if stream {
+        loop {
+            match updates.recv().await {
+                Ok(update) => {
+                    client
+                        .write_all(format!("{}\n", update.to_json()).as_bytes())
+                        .await?
+                }
+                // A slow client skips what it missed rather than blocking the feed
+                Err(RecvError::Lagged(_)) => continue,
+                Err(RecvError::Closed) => break,
+            }
+        }
+    }
+    client.shutdown().await?;
+    Ok(())
+}
@@ -0,0 +1,53 @@
+use std::collections::HashMap;
+use std::sync::Mutex;
+
+use serde_json::Value;
+use tokio::sync::broadcast;
+
+use crate::data::CoinUpdate;
+
+/// Updates buffered per streaming client before it starts missing some.
+const STREAM_BUFFER: usize = 1024;
+
+/// Latest update per coin and venue, shared with local clients of the
+/// snapshot socket, plus a broadcast of every update as it arrives.
+pub struct SharedSnapshot {
+    latest: Mutex<HashMap<(String, u8), CoinUpdate>>,
+    updates: broadcast::Sender<CoinUpdate>,
+}
+
+impl SharedSnapshot {
+    pub fn new() -> Self {
+        Self {
+            latest: Mutex::new(HashMap::new()),
+            updates: broadcast::channel(STREAM_BUFFER).0,
+        }
+    }
+
+    pub fn record(&self, update: &CoinUpdate) {
+        self.latest
+            .lock()
+            .unwrap()
+            .insert((update.coin.clone(), update.venue), update.clone());
+        // No receivers just means no client is streaming
+        let _ = self.updates.send(update.clone());
+    }
+
+    /// Every latest update, ordered by coin then venue.
+    pub fn to_json(&self) -> Value {
+        let latest = self.latest.lock().unwrap();
+        let mut updates: Vec<&CoinUpdate> = latest.values().collect();
+        updates.sort_by(|a, b| a.coin.cmp(&b.coin).then(a.venue.cmp(&b.venue)));
+        Value::Array(updates.into_iter().map(CoinUpdate::to_json).collect())
+    }
+
+    pub fn subscribe(&self) -> broadcast::Receiver<CoinUpdate> {
+        self.updates.subscribe()
+    }
+}
+
+impl Default for SharedSnapshot {
+    fn default() -> Self {
+        Self::new()
+    }
+}
@@ -0,0 +1,5 @@
+// Root
+pub const HYPERLIQUID_INFO_API: &str = "https://api.hyperliquid.xyz/info";
+
+// Request bodies
+pub const HYPERLIQUID_META_REQUEST: &str = r#"{"type":"meta"}"#;
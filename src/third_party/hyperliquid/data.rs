@@ -0,0 +1,84 @@
+use serde::Deserialize;
+
+/// Response to the `meta` info request.
+#[derive(Debug, Deserialize)]
+pub struct PerpMeta {
+    pub universe: Vec<UniverseAsset>,
+}
+
+/// One universe entry. Perp entries carry size decimals and a max leverage;
+/// spot pairs (`@<index>`, `BASE/QUOTE`) carry neither, and delisted perps
+/// are flagged.
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct UniverseAsset {
+    pub name: String,
+    pub sz_decimals: Option<u32>,
+    pub max_leverage: Option<u32>,
+    #[serde(default)]
+    pub only_isolated: bool,
+    #[serde(default)]
+    pub is_delisted: bool,
+}
+
+impl UniverseAsset {
+    /// Why the entry will never stream perp asset contexts, or `None` for a
+    /// tradable perp.
+    pub fn skip_reason(&self) -> Option<&'static str> {
+        if self.sz_decimals.is_none() || self.max_leverage.is_none_or(|l| l == 0) {
+            Some("not a perp")
+        } else if self.is_delisted {
+            Some("delisted")
+        } else {
+            None
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse(json: &str) -> UniverseAsset {
+        serde_json::from_str(json).unwrap()
+    }
+
+    #[test]
+    fn perps_are_kept_whatever_their_name() {
+        for json in [
+            r#"{"name":"BTC","szDecimals":5,"maxLeverage":40}"#,
+            r#"{"name":"kPEPE","szDecimals":0,"maxLeverage":10,"onlyIsolated":true}"#,
+            r#"{"name":"xyz:TSLA","szDecimals":3,"maxLeverage":10}"#,
+        ] {
+            assert_eq!(parse(json).skip_reason(), None, "{}", json);
+        }
+    }
+
+    #[test]
+    fn spot_and_index_entries_are_skipped() {
+        for json in [
+            r#"{"name":"@107","tokens":[150,0],"index":107,"isCanonical":false}"#,
+            r#"{"name":"PURR/USDC","tokens":[1,0],"index":0,"isCanonical":true}"#,
+            r#"{"name":"BTC","szDecimals":5,"maxLeverage":0}"#,
+        ] {
+            assert_eq!(parse(json).skip_reason(), Some("not a perp"), "{}", json);
+        }
+    }
+
+    #[test]
+    fn delisted_perps_are_skipped() {
+        let asset = parse(r#"{"name":"MATIC","szDecimals":1,"maxLeverage":20,"isDelisted":true}"#);
+        assert_eq!(asset.skip_reason(), Some("delisted"));
+    }
+
+    #[test]
+    fn meta_response_parses_with_extra_fields() {
+        let meta: PerpMeta = serde_json::from_str(
+            r#"{"universe":[{"name":"ETH","szDecimals":4,"maxLeverage":25,"marginTableId":25}],
+                "marginTables":[]}"#,
+        )
+        .unwrap();
+        assert_eq!(meta.universe.len(), 1);
+        assert_eq!(meta.universe[0].skip_reason(), None);
+    }
+}
@@ -0,0 +1,2 @@
+pub mod api_path;
+pub mod data;
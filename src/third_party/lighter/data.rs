@@ -1,6 +1,8 @@
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 
+use crate::data::is_valid_price;
+
 #[derive(Debug, Deserialize)]
 pub struct ApiFundingRatesResponse {
     pub code: u16,
@@ -23,6 +25,30 @@ pub struct MarketStatsMessage {
     pub message_type: String,
 }
 
+/// Update from a single-market `market_stats/<id>` channel.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct MarketStatMessage {
+    pub channel: String,
+    pub market_stats: MarketStatEntry,
+    #[serde(rename = "type")]
+    pub message_type: String,
+}
+
+impl From<MarketStatMessage> for MarketStatsMessage {
+    fn from(message: MarketStatMessage) -> Self {
+        let mut market_stats = HashMap::new();
+        market_stats.insert(
+            message.market_stats.market_id.to_string(),
+            message.market_stats,
+        );
+        Self {
+            channel: message.channel,
+            market_stats,
+            message_type: message.message_type,
+        }
+    }
+}
+
 #[derive(Debug, Serialize, Deserialize)]
 pub struct MarketStatEntry {
     pub market_id: u64,
@@ -42,3 +68,198 @@ pub struct MarketStatEntry {
     pub daily_price_high: f64,
     pub daily_price_change: f64,
 }
+
+impl MarketStatEntry {
+    /// Open interest in base units. Lighter reports OI in USD; without a
+    /// usable mark price there is no base amount to derive, so this is zero
+    /// rather than the result of dividing by zero.
+    pub fn base_open_interest(&self) -> f64 {
+        let price = self.mark_price.parse::<f64>().unwrap_or(0.0);
+        if !is_valid_price(price) {
+            return 0.0;
+        }
+        (self.open_interest.parse::<f64>().unwrap_or(0.0) / price) * 2.0f64
+    }
+}
+
+/// A text frame from the Lighter stream, told apart by its `type` field.
+#[derive(Debug)]
+pub enum LighterFrame {
+    /// Full state sent along with a subscription confirmation.
+    Snapshot(MarketStatsMessage),
+    /// Only the markets that changed; markets left out keep their values.
+    Update(MarketStatsMessage),
+    /// Subscription confirmation without market data, for the channel.
+    Subscribed(String),
+    /// Connection notices, errors and other frames without market data.
+    Control(serde_json::Value),
+}
+
+impl LighterFrame {
+    /// Classifies a frame. Market data in either the `all` or the
+    /// single-market shape is normalized to `MarketStatsMessage`; an update
+    /// whose data doesn't parse is an error.
+    pub fn parse(text: &str) -> serde_json::Result<Self> {
+        let value: serde_json::Value = serde_json::from_str(text)?;
+        let frame_type = value["type"].as_str().unwrap_or_default();
+        let has_stats = match &value["market_stats"] {
+            serde_json::Value::Object(stats) => !stats.is_empty(),
+            _ => false,
+        };
+        if frame_type.starts_with("subscribed") {
+            if !has_stats {
+                let channel = value["channel"].as_str().unwrap_or_default().to_string();
+                return Ok(Self::Subscribed(channel));
+            }
+            return Self::market_stats(value).map(Self::Snapshot);
+        }
+        if frame_type.starts_with("update") {
+            return Self::market_stats(value).map(Self::Update);
+        }
+        Ok(Self::Control(value))
+    }
+
+    fn market_stats(value: serde_json::Value) -> serde_json::Result<MarketStatsMessage> {
+        serde_json::from_value::<MarketStatsMessage>(value.clone()).or_else(|_| {
+            serde_json::from_value::<MarketStatMessage>(value).map(MarketStatsMessage::from)
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn entry(mark_price: &str, open_interest: &str) -> MarketStatEntry {
+        serde_json::from_value(serde_json::json!({
+            "market_id": 1,
+            "index_price": mark_price,
+            "mark_price": mark_price,
+            "open_interest": open_interest,
+            "open_interest_limit": "0",
+            "funding_clamp_small": "0",
+            "funding_clamp_big": "0",
+            "last_trade_price": mark_price,
+            "current_funding_rate": "0.0012",
+            "funding_rate": "0.0010",
+            "funding_timestamp": 1_700_000_000_000i64,
+            "daily_base_token_volume": 0.0,
+            "daily_quote_token_volume": 0.0,
+            "daily_price_low": 0.0,
+            "daily_price_high": 0.0,
+            "daily_price_change": 0.0
+        }))
+        .unwrap()
+    }
+
+    fn stats_json(market_id: u64) -> serde_json::Value {
+        let mut stats = serde_json::to_value(entry("2000", "1000000")).unwrap();
+        stats["market_id"] = market_id.into();
+        stats
+    }
+
+    #[test]
+    fn subscribe_ack_without_stats_is_subscribed() {
+        for stats in [serde_json::json!({}), serde_json::Value::Null] {
+            let text = serde_json::json!({
+                "type": "subscribed/market_stats",
+                "channel": "market_stats:all",
+                "market_stats": stats
+            })
+            .to_string();
+            match LighterFrame::parse(&text).unwrap() {
+                LighterFrame::Subscribed(channel) => assert_eq!(channel, "market_stats:all"),
+                frame => panic!("expected Subscribed, got {:?}", frame),
+            }
+        }
+    }
+
+    #[test]
+    fn subscribe_ack_with_stats_is_snapshot() {
+        let text = serde_json::json!({
+            "type": "subscribed/market_stats",
+            "channel": "market_stats:all",
+            "market_stats": { "0": stats_json(0), "1": stats_json(1) }
+        })
+        .to_string();
+        match LighterFrame::parse(&text).unwrap() {
+            LighterFrame::Snapshot(message) => assert_eq!(message.market_stats.len(), 2),
+            frame => panic!("expected Snapshot, got {:?}", frame),
+        }
+    }
+
+    #[test]
+    fn partial_update_keeps_only_changed_markets() {
+        let text = serde_json::json!({
+            "type": "update/market_stats",
+            "channel": "market_stats:all",
+            "market_stats": { "1": stats_json(1) }
+        })
+        .to_string();
+        match LighterFrame::parse(&text).unwrap() {
+            LighterFrame::Update(message) => {
+                assert_eq!(message.market_stats.len(), 1);
+                assert_eq!(message.market_stats["1"].market_id, 1);
+            }
+            frame => panic!("expected Update, got {:?}", frame),
+        }
+    }
+
+    #[test]
+    fn single_market_update_is_normalized() {
+        let text = serde_json::json!({
+            "type": "update/market_stats",
+            "channel": "market_stats:7",
+            "market_stats": stats_json(7)
+        })
+        .to_string();
+        match LighterFrame::parse(&text).unwrap() {
+            LighterFrame::Update(message) => {
+                assert_eq!(message.channel, "market_stats:7");
+                assert_eq!(message.market_stats["7"].market_id, 7);
+            }
+            frame => panic!("expected Update, got {:?}", frame),
+        }
+    }
+
+    #[test]
+    fn frames_without_market_data_are_control() {
+        for text in [
+            r#"{"type":"connected","session_id":"abc"}"#,
+            r#"{"type":"ping"}"#,
+            r#"{"error":{"code":30005,"message":"Invalid channel"}}"#,
+        ] {
+            assert!(
+                matches!(LighterFrame::parse(text).unwrap(), LighterFrame::Control(_)),
+                "{}",
+                text
+            );
+        }
+    }
+
+    #[test]
+    fn malformed_frames_are_errors() {
+        assert!(LighterFrame::parse("not json").is_err());
+        assert!(LighterFrame::parse(r#"{"type":"update/market_stats""#).is_err());
+        // An update whose market data doesn't match either shape
+        assert!(
+            LighterFrame::parse(
+                r#"{"type":"update/market_stats","channel":"market_stats:all","market_stats":{"0":{"market_id":"zero"}}}"#
+            )
+            .is_err()
+        );
+    }
+
+    #[test]
+    fn base_open_interest_divides_usd_by_mark_price() {
+        assert_eq!(entry("2000", "1000000").base_open_interest(), 1000.0);
+    }
+
+    #[test]
+    fn base_open_interest_is_zero_without_a_usable_price() {
+        for price in ["0", "0.0", "-1", "NaN", "inf", "not a number"] {
+            let oi = entry(price, "1000000").base_open_interest();
+            assert_eq!(oi, 0.0, "mark price {:?}", price);
+        }
+    }
+}
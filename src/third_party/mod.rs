@@ -1,2 +1,3 @@
+pub mod hyperliquid;
 pub mod lighter;
 pub use lighter::*;
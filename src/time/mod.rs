@@ -0,0 +1,3 @@
+pub mod timezone;
+
+pub use timezone::Timezone;
@@ -0,0 +1,37 @@
+use chrono::{DateTime, Local, Utc};
+
+use crate::config::TIMEZONE;
+
+/// Timezone used for every time shown to the user. Funding settles on UTC
+/// boundaries, so settlement math always uses UTC regardless of this choice.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Timezone {
+    Utc,
+    Local,
+}
+
+impl Timezone {
+    /// The configured display timezone.
+    pub fn display() -> Self {
+        TIMEZONE
+    }
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            Timezone::Utc => "UTC",
+            Timezone::Local => "local",
+        }
+    }
+
+    /// Formats a UTC instant in this timezone with a `strftime` pattern.
+    pub fn format(&self, time: DateTime<Utc>, fmt: &str) -> String {
+        match self {
+            Timezone::Utc => time.format(fmt).to_string(),
+            Timezone::Local => time.with_timezone(&Local).format(fmt).to_string(),
+        }
+    }
+
+    pub fn format_now(&self, fmt: &str) -> String {
+        self.format(Utc::now(), fmt)
+    }
+}
@@ -2,46 +2,173 @@ use color_eyre::Result;
 use crossterm::event::{self, Event, KeyCode, KeyEventKind, KeyModifiers};
 use ratatui::{
     DefaultTerminal, Frame,
-    layout::{Alignment, Constraint, Flex, Layout, Margin, Rect},
-    style::{Modifier, Style, Stylize},
+    layout::{Alignment, Constraint, Direction, Flex, Layout, Margin, Rect, Size},
+    style::{Color, Modifier, Style, Stylize},
+    text::{Line, Span},
     widgets::{
-        Block, BorderType, Cell, Clear, HighlightSpacing, Paragraph, Row, Scrollbar,
-        ScrollbarOrientation, ScrollbarState, Table, TableState,
+        Bar, BarChart, BarGroup, Block, BorderType, Cell, Clear, HighlightSpacing, LineGauge, List,
+        ListState, Paragraph, Row, Scrollbar, ScrollbarOrientation, ScrollbarState, Table,
+        TableState, Tabs, Wrap,
     },
 };
-use std::fs::OpenOptions;
-use std::io::Write;
+use serde_json::json;
+use std::collections::{HashMap, HashSet};
+use std::path::PathBuf;
 use std::sync::{Arc, Mutex};
 use std::time::Duration;
 use tokio::sync::mpsc;
 use tokio::time::Instant;
 
-use crate::config::{ERROR_POPUP_DURATION_MS, INFO_TEXT, ITEM_HEIGHT, PALETTES, POLL_DURATION_MS};
-use crate::data::CoinData;
-use crate::ui::TableColors;
+use crate::alert::{
+    AlertDispatch, AlertEvent, AlertHistory, AlertKind, apply_update, initial_threshold,
+    is_alerting, load_muted, save_muted,
+};
+use crate::config::{
+    ALERT_HISTORY_CAPACITY, ALIGN_NUMERIC_COLUMNS, AUTO_SCROLL, AUTO_SCROLL_INTERVAL_MS,
+    AUTO_SCROLL_PAUSE_MS, BASELINE_APR, BASIS_PERIOD_HOURS, CARRY_WEIGHTS, CLEAR_ON_RESIZE,
+    COLOR_MODE, COLUMN_SEPARATORS, COMPACT_NUMBERS, COMPOUND_ANNUALIZATION, COUNTDOWN_BAR_WIDTH,
+    DEBUG_LOG_FILE, DEFAULT_SORT_COLUMN, DETAIL_HISTORY, DIAGNOSTICS_KEY, DIAGNOSTICS_LOG_LINES,
+    DIM_UNSORTED_COLUMNS, DIVERGENCE_MIN_SAMPLES, DIVERGENCE_RULE, ERROR_POPUP_DURATION_MS,
+    FAVORITES_SORT, FOLLOW_SELECTION, FUNDING_FLASH_DURATION_MS, FUNDING_PERIODS,
+    FUNDING_RANK_REFRESH_MS, FUNDING_RATE_THRESHOLD, GROUP_BY, GROUP_ROWS, HIDE_PEGGED, INFO_TEXT,
+    INITIAL_LAYOUT, INITIAL_SELECTION, ITEM_HEIGHT, MAX_FPS, MULTI_PERIOD_FUNDING, OI_BOTH_UNITS,
+    OPEN_TRADE_PAGE_KEY, PAGE_OVERLAP_ROWS, PALETTES, POLL_DURATION_MS, POSITIONS,
+    PRICE_STALE_SECS, REPEAT_HEADER, REPEAT_HEADER_INTERVAL, SELECTION_STYLE, SHOW_LISTING_CHANGES,
+    SHOW_MARKET_INDEX, SHOW_MUTED_MARKER, SHOW_NET_OF_BASELINE, SMOOTH_FUNDING, SORT_GRACE_MS,
+    TABS, THRESHOLD_FLASH_COLOR, THRESHOLD_FLASH_DURATION_MS, THRESHOLD_STATE_FILE,
+    TRADE_URL_TEMPLATES, TREND_DEAD_BAND, TREND_LOOKBACK_SECS, WRAP_NAVIGATION, YIELD_NOTIONAL_USD,
+};
+use crate::data::{
+    CarryInputs, CoinData, CoinUpdate, Divergence, DivergenceRule, FundingRateRound, MarketIndex,
+    UpdateReceiver, VENUES, annualize, canonical_symbol, deannualize, display_symbol,
+    funding_convention, is_pegged, is_valid_price, position_for, precision_for, trade_url,
+    venue_name, venue_short_name,
+};
+use crate::exchange::exchanges_for;
+use crate::health::FeedHealth;
+use crate::paths::{data_file, save_state, write_debug_log};
+use crate::request::dump_universe;
+use crate::time::Timezone;
+use crate::ui::{
+    ColorMode, Column, FavoritesSort, GroupBy, InitialSelection, Prompt, SavedLayout, SearchScope,
+    TabConfig, TabView, TableColors, delete_layout, divergence_text, help, histogram,
+    load_favorites, load_layouts, navigation, save_favorites, save_layout, sorting,
+};
 
 fn log_debug(msg: String) {
-    if let Ok(mut file) = OpenOptions::new()
-        .create(true)
-        .append(true)
-        .open("/tmp/hype_debug.log")
-    {
-        let _ = writeln!(
-            file,
-            "[{}] UI: {}",
-            chrono::Local::now().format("%H:%M:%S"),
-            msg
-        );
+    write_debug_log("UI", &msg);
+}
+
+/// Summarizes coins added to and removed from a venue listing, e.g.
+/// `+2 new: X, Y; -1 removed: W`. `None` when the listings match.
+fn listing_changes(old: &[String], new: &[String]) -> Option<String> {
+    let added: Vec<&str> = new
+        .iter()
+        .filter(|c| !old.contains(c))
+        .map(String::as_str)
+        .collect();
+    let removed: Vec<&str> = old
+        .iter()
+        .filter(|c| !new.contains(c))
+        .map(String::as_str)
+        .collect();
+
+    let mut parts = Vec::new();
+    if !added.is_empty() {
+        parts.push(format!("+{} new: {}", added.len(), added.join(", ")));
+    }
+    if !removed.is_empty() {
+        parts.push(format!(
+            "-{} removed: {}",
+            removed.len(),
+            removed.join(", ")
+        ));
+    }
+    (!parts.is_empty()).then(|| parts.join("; "))
+}
+
+/// `BASELINE_APR` as an hourly fraction, comparable to hourly funding.
+fn baseline_hourly() -> f64 {
+    BASELINE_APR / FundingRateRound::Annually.hours()
+}
+
+/// A number abbreviated with a K/M/B suffix. Numbers without a suffix get
+/// a trailing space so digits line up in a right-aligned column.
+fn format_compact(value: f64) -> String {
+    let magnitude = value.abs();
+    if magnitude >= 1_000_000_000.0 {
+        format!("{:.2}B", value / 1_000_000_000.0)
+    } else if magnitude >= 1_000_000.0 {
+        format!("{:.2}M", value / 1_000_000.0)
+    } else if magnitude >= 1_000.0 {
+        format!("{:.2}K", value / 1_000.0)
+    } else {
+        format!("{:.2} ", value)
+    }
+}
+
+/// Compact USD amount, e.g. `$148.02M`.
+fn format_usd(usd: f64) -> String {
+    format!("${}", format_compact(usd))
+}
+
+/// Time left as `mm:ss`, or `h:mm:ss` from an hour up.
+fn format_countdown(secs: i64) -> String {
+    let (hours, minutes, seconds) = (secs / 3600, secs % 3600 / 60, secs % 60);
+    if hours > 0 {
+        format!("{}:{:02}:{:02}", hours, minutes, seconds)
+    } else {
+        format!("{:02}:{:02}", minutes, seconds)
+    }
+}
+
+/// Text progress bar `width` cells wide, filled to `progress` in `[0, 1]`.
+fn progress_bar(progress: f64, width: usize) -> String {
+    let filled = ((progress * width as f64).round() as usize).min(width);
+    format!("{}{}", "█".repeat(filled), "░".repeat(width - filled))
+}
+
+/// A coin amount with thousands separators, whole units from 1,000 up.
+fn format_amount(amount: f64) -> String {
+    if amount.abs() < 1_000.0 {
+        return amount.to_string();
+    }
+    let digits = format!("{:.0}", amount.abs());
+    let mut grouped = String::new();
+    for (i, digit) in digits.chars().enumerate() {
+        if i > 0 && (digits.len() - i) % 3 == 0 {
+            grouped.push(',');
+        }
+        grouped.push(digit);
+    }
+    if amount < 0.0 {
+        format!("-{}", grouped)
+    } else {
+        grouped
     }
 }
 
-enum FundingRateRound {
-    Hourly,
-    QuadriHourly,
-    OctaHourly,
-    Daily,
-    Monthly,
-    Annually,
+/// Right-aligns numeric columns when configured, so fixed-precision values
+/// line up on the decimal point.
+fn aligned(column: Column, text: String) -> Line<'static> {
+    if ALIGN_NUMERIC_COLUMNS && column.is_numeric() {
+        Line::from(text).right_aligned()
+    } else {
+        Line::from(text)
+    }
+}
+
+/// Puts `separator` between consecutive items when column separators are
+/// enabled.
+fn separated<T: Clone>(items: impl IntoIterator<Item = T>, separator: T) -> Vec<T> {
+    let mut out = Vec::new();
+    for (i, item) in items.into_iter().enumerate() {
+        if COLUMN_SEPARATORS && i > 0 {
+            out.push(separator.clone());
+        }
+        out.push(item);
+    }
+    out
 }
 
 pub struct TuiApp {
@@ -52,14 +179,88 @@ pub struct TuiApp {
     round: FundingRateRound,
     color_index: usize,
     symbol: bool,
+    oi_both_units: bool,
+    compact_numbers: bool,
     popup: bool,
     popup_message: String,
     exchange: Arc<Mutex<u8>>,
     exchange_tx: mpsc::UnboundedSender<u8>,
     all_coins: Vec<String>,
     visible_coins: Vec<String>,
-    coin_list_rx: mpsc::UnboundedReceiver<Vec<String>>,
-    error_popup_timer: Option<tokio::time::Instant>,
+    coin_list_rx: mpsc::UnboundedReceiver<(u64, Vec<String>)>,
+    /// Feed generation streaming the current coin list.
+    feed_generation: u64,
+    notice: Option<(String, Instant)>,
+    /// What the last quiet window held back, shown in the footer until the
+    /// next one starts.
+    quiet_summary: Option<String>,
+    notice_duration: Duration,
+    /// Results of background work (e.g. the universe export), shown as
+    /// notices when they arrive.
+    notice_tx: mpsc::UnboundedSender<String>,
+    notice_rx: mpsc::UnboundedReceiver<String>,
+    repeat_header: bool,
+    alerts: AlertHistory,
+    alert_dispatch: AlertDispatch,
+    muted: HashSet<String>,
+    favorites: Vec<String>,
+    wrap_navigation: bool,
+    search_scope: SearchScope,
+    show_venue_age: bool,
+    flash_duration: Duration,
+    /// When each coin last crossed the alert threshold, for the row flash.
+    crossed_at: HashMap<String, Instant>,
+    compound: bool,
+    smoothed: bool,
+    sorted_column: Option<Column>,
+    /// Re-sorting is held off until then while early data streams in.
+    sort_grace_until: Option<Instant>,
+    dim_unsorted_columns: bool,
+    tabs: Vec<TabView>,
+    active_tab: usize,
+    detail_popup: bool,
+    histogram: bool,
+    help: bool,
+    color_mode: ColorMode,
+    group_by: Option<GroupBy>,
+    show_funding_rank: bool,
+    funding_ranks: HashMap<String, f64>,
+    ranks_refreshed_at: Option<Instant>,
+    show_basis: bool,
+    show_premium: bool,
+    show_funding_delta: bool,
+    show_market_index: bool,
+    show_trend: bool,
+    show_daily_change: bool,
+    show_daily_yield: bool,
+    show_countdown: bool,
+    show_carry: bool,
+    show_divergence: bool,
+    show_last_price: bool,
+    show_net: bool,
+    multi_period: bool,
+    show_blended: bool,
+    show_venue_count: bool,
+    follow_selection: bool,
+    alerting_only: bool,
+    filter_inverted: bool,
+    hide_pegged: bool,
+    /// Selection still to apply at startup; cleared once applied.
+    initial_selection: Option<InitialSelection>,
+    layout_picker: bool,
+    layouts: Vec<SavedLayout>,
+    layout_state: ListState,
+    refresh_pending: bool,
+    dropped_updates: u64,
+    prompt: Prompt,
+    funding_threshold: f64,
+    clipboard: Option<arboard::Clipboard>,
+    health: Arc<FeedHealth>,
+    page_rows: usize,
+    auto_scroll: bool,
+    /// When auto-scroll next advances; pushed back by keypresses.
+    auto_scroll_at: Instant,
+    column_offset: usize,
 }
 
 impl TuiApp {
@@ -67,16 +268,19 @@ impl TuiApp {
         coins: Vec<String>,
         exchange: Arc<Mutex<u8>>,
         exchange_tx: mpsc::UnboundedSender<u8>,
-        all_coins: Vec<String>,
-        coin_list_rx: mpsc::UnboundedReceiver<Vec<String>>,
+        coin_list_rx: mpsc::UnboundedReceiver<(u64, Vec<String>)>,
+        health: Arc<FeedHealth>,
     ) -> Self {
-        let visible_coins = coins.clone();
+        // Venue lists use native symbols; rows are keyed by canonical names
+        let all_coins: Vec<String> = coins.iter().map(|c| canonical_symbol(c)).collect();
+        let visible_coins = all_coins.clone();
         let items = all_coins
             .iter()
             .map(|c| CoinData::new(c.clone()))
             .collect::<Vec<_>>();
+        let (notice_tx, notice_rx) = mpsc::unbounded_channel();
 
-        Self {
+        let mut app = Self {
             state: TableState::default().with_selected(0),
             scroll_state: ScrollbarState::new(
                 (visible_coins.len().saturating_sub(1)) * ITEM_HEIGHT,
@@ -86,6 +290,8 @@ impl TuiApp {
             color_index: 0,
             items,
             symbol: false,
+            oi_both_units: OI_BOTH_UNITS,
+            compact_numbers: COMPACT_NUMBERS,
             popup: false,
             popup_message: String::new(),
             exchange,
@@ -93,8 +299,88 @@ impl TuiApp {
             all_coins,
             visible_coins,
             coin_list_rx,
-            error_popup_timer: None,
+            feed_generation: 0,
+            notice: None,
+            quiet_summary: None,
+            notice_duration: Duration::from_millis(ERROR_POPUP_DURATION_MS),
+            notice_tx,
+            notice_rx,
+            repeat_header: REPEAT_HEADER,
+            alerts: AlertHistory::new(ALERT_HISTORY_CAPACITY),
+            alert_dispatch: AlertDispatch::from_config(),
+            muted: load_muted(),
+            favorites: load_favorites(),
+            wrap_navigation: WRAP_NAVIGATION,
+            search_scope: SearchScope::default(),
+            show_venue_age: false,
+            flash_duration: Duration::from_millis(FUNDING_FLASH_DURATION_MS),
+            crossed_at: HashMap::new(),
+            compound: COMPOUND_ANNUALIZATION,
+            smoothed: SMOOTH_FUNDING,
+            sorted_column: DEFAULT_SORT_COLUMN,
+            sort_grace_until: Some(Instant::now() + Duration::from_millis(SORT_GRACE_MS)),
+            dim_unsorted_columns: DIM_UNSORTED_COLUMNS,
+            tabs: vec![TabView::default(); TABS.len().max(1)],
+            active_tab: 0,
+            detail_popup: false,
+            histogram: false,
+            help: false,
+            color_mode: COLOR_MODE,
+            group_by: GROUP_ROWS.then_some(GROUP_BY),
+            show_funding_rank: false,
+            funding_ranks: HashMap::new(),
+            ranks_refreshed_at: None,
+            show_basis: false,
+            show_premium: false,
+            show_funding_delta: false,
+            show_market_index: SHOW_MARKET_INDEX,
+            show_trend: false,
+            show_daily_change: false,
+            show_daily_yield: false,
+            show_countdown: false,
+            show_carry: false,
+            show_divergence: false,
+            show_last_price: false,
+            show_net: SHOW_NET_OF_BASELINE,
+            multi_period: MULTI_PERIOD_FUNDING && !FUNDING_PERIODS.is_empty(),
+            show_blended: false,
+            show_venue_count: false,
+            follow_selection: FOLLOW_SELECTION,
+            alerting_only: false,
+            filter_inverted: false,
+            hide_pegged: HIDE_PEGGED,
+            initial_selection: Some(INITIAL_SELECTION),
+            layout_picker: false,
+            layouts: Vec::new(),
+            layout_state: ListState::default(),
+            refresh_pending: false,
+            dropped_updates: 0,
+            prompt: Prompt::default(),
+            funding_threshold: initial_threshold(),
+            clipboard: None,
+            health,
+            page_rows: 1,
+            auto_scroll: AUTO_SCROLL,
+            auto_scroll_at: Instant::now() + Duration::from_millis(AUTO_SCROLL_INTERVAL_MS),
+            column_offset: 0,
+        };
+        app.arrange_rows();
+        if let Some(name) = INITIAL_LAYOUT {
+            match load_layouts().into_iter().find(|l| l.name == name) {
+                Some(layout) => app.apply_layout(&layout),
+                None => log_debug(format!("Initial layout \"{}\" not found", name)),
+            }
+        }
+        app.apply_initial_selection();
+        for (setting, key) in help::shadowed_keys() {
+            let message = format!(
+                "{} '{}' is already a built-in key and does nothing; pick another",
+                setting, key
+            );
+            log_debug(message.clone());
+            app.show_notice(message);
         }
+        app
     }
 
     fn get_visible_coins(&self, _exchange: u8) -> Vec<String> {
@@ -103,6 +389,16 @@ impl TuiApp {
     }
 
     fn update_coin_list(&mut self, new_coins: Vec<String>) {
+        // Venue lists use native symbols; rows are keyed by canonical names
+        let new_coins: Vec<String> = new_coins.iter().map(|c| canonical_symbol(c)).collect();
+        let refreshed = std::mem::take(&mut self.refresh_pending);
+        if refreshed && SHOW_LISTING_CHANGES {
+            let message = listing_changes(&self.all_coins, &new_coins)
+                .unwrap_or_else(|| "No listing changes".to_string());
+            log_debug(format!("Coin list refreshed: {}", message));
+            self.show_notice(message);
+        }
+        let followed = self.selected_coin().map(|c| c.coin.clone());
         // Update all_coins with the new list
         self.all_coins = new_coins.clone();
         // Update visible_coins
@@ -117,30 +413,65 @@ impl TuiApp {
             }
         }
         self.items = new_items;
-        // Reset selection and scrollbar
-        self.state.select(Some(0));
+        // Reset selection and scrollbar, keeping the cursor across a refresh
         self.update_scrollbar_size();
+        if !(refreshed && followed.is_some_and(|coin| self.select_coin(&coin))) {
+            self.state.select(Some(0));
+        }
+        self.resort();
     }
 
-    fn update_coin(
-        &mut self,
-        coin: &str,
-        funding: f64,
-        open_interest: f64,
-        oracle_price: f64,
-        exchange: u8,
-    ) {
+    /// Asks the websocket manager to re-fetch the current exchange's coin
+    /// list; the result arrives through `coin_list_rx`.
+    fn refresh_coin_list(&mut self) {
+        self.refresh_pending = true;
+        let _ = self.exchange_tx.send(self.get_exchange());
+    }
+
+    fn update_coin(&mut self, update: CoinUpdate) {
+        if !update.is_current(self.get_exchange(), self.feed_generation) {
+            return;
+        }
         // Filter updates based on visible coins
-        if !self.visible_coins.contains(&coin.to_string()) {
+        if !self.visible_coins.contains(&update.coin) {
             return;
         }
 
-        if let Some(c) = self.items.iter_mut().find(|c| c.coin == coin) {
-            c.update_with_exchange(funding, open_interest, oracle_price, exchange);
+        if let Some(c) = self.items.iter_mut().find(|c| c.coin == update.coin) {
+            let events = apply_update(c, &update, self.funding_threshold, &self.muted);
+            // Flash the row for the same crossings that raise alerts
+            if events
+                .iter()
+                .any(|e| e.kind == AlertKind::ThresholdCrossing)
+            {
+                self.crossed_at.insert(update.coin.clone(), Instant::now());
+            }
+            if c.is_funding_suspect() {
+                log_debug(format!(
+                    "Suspect funding for {} from venue {}: {}",
+                    update.coin, update.venue, update.funding
+                ));
+            }
+            self.fire_alerts(events);
             self.update_scrollbar_size();
         }
     }
 
+    /// Hands alerts to the notification sinks and records those that pass
+    /// the quiet-hours gate, noting any released from a window that just
+    /// ended.
+    fn fire_alerts(&mut self, events: Vec<AlertEvent>) {
+        let (events, summary) = self.alert_dispatch.dispatch(events);
+        if summary.is_some() {
+            self.quiet_summary = summary;
+        } else if self.quiet_summary.is_some() && self.alert_dispatch.is_quiet() {
+            self.quiet_summary = None;
+        }
+        for event in events {
+            self.alerts.push(event);
+        }
+    }
+
     pub fn get_exchange(&self) -> u8 {
         *self.exchange.lock().unwrap()
     }
@@ -170,48 +501,305 @@ impl TuiApp {
         ));
         let _ = self.exchange_tx.send(exchange);
         log_debug("Exchange sent to channel".to_string());
+        // Let the new venue's data arrive before sorting again
+        self.sort_grace_until = Some(Instant::now() + Duration::from_millis(SORT_GRACE_MS));
         // Reset selection to first visible item
         self.state.select(Some(0));
         // Update scrollbar size for the filtered items
         self.update_scrollbar_size();
     }
 
+    fn active_tab_config(&self) -> Option<&'static TabConfig> {
+        TABS.get(self.active_tab)
+    }
+
+    /// Whether a coin belongs to the active exchange and tab, regardless of
+    /// the alerting-only filter.
+    fn is_in_view(&self, c: &CoinData) -> bool {
+        self.is_on_exchange(c)
+            && self
+                .active_tab_config()
+                .is_none_or(|tab| tab.includes(&c.coin))
+    }
+
+    /// Whether a coin has data and is listed on the active exchange.
+    fn is_on_exchange(&self, c: &CoinData) -> bool {
+        c.has_data() && self.visible_coins.contains(&c.coin)
+    }
+
+    /// Whether a coin passes every active filter: the tab, hidden pegged
+    /// coins and the alerting-only filter.
+    fn passes_filters(&self, c: &CoinData) -> bool {
+        self.active_tab_config()
+            .is_none_or(|tab| tab.includes(&c.coin))
+            && !(self.hide_pegged && is_pegged(&c.coin))
+            && (!self.alerting_only || self.is_coin_alerting(c))
+    }
+
+    /// Whether any filter narrows the exchange's coins.
+    fn has_active_filter(&self) -> bool {
+        self.alerting_only
+            || self.hide_pegged
+            || self
+                .active_tab_config()
+                .is_some_and(|tab| !tab.coins.is_empty())
+    }
+
+    /// Whether the rows show the complement of the active filters.
+    fn is_filter_inverted(&self) -> bool {
+        self.filter_inverted && self.has_active_filter()
+    }
+
+    /// Whether a coin is rendered as a row in the active view. An inverted
+    /// filter keeps the exchange's coins that the combined filter drops.
+    fn is_row_visible(&self, c: &CoinData) -> bool {
+        self.is_on_exchange(c) && self.passes_filters(c) != self.is_filter_inverted()
+    }
+
+    /// Number of pegged coins in the active view kept out of the table.
+    fn hidden_pegged_count(&self) -> usize {
+        if !self.hide_pegged {
+            return 0;
+        }
+        self.items
+            .iter()
+            .filter(|c| self.is_in_view(c) && is_pegged(&c.coin))
+            .count()
+    }
+
+    /// Whether a coin's funding is past the alert threshold and trusted.
+    fn is_coin_alerting(&self, c: &CoinData) -> bool {
+        !c.is_funding_suspect() && is_alerting(c.alert_funding(), self.funding_threshold)
+    }
+
+    /// Number of coins in the active view currently past the alert threshold.
+    fn alerting_count(&self) -> usize {
+        self.items
+            .iter()
+            .filter(|c| self.is_in_view(c) && self.is_coin_alerting(c))
+            .count()
+    }
+
+    /// The coin under the cursor in the active view.
+    fn selected_coin(&self) -> Option<&CoinData> {
+        let selected = self.state.selected()?;
+        self.items
+            .iter()
+            .filter(|c| self.is_row_visible(c))
+            .nth(selected)
+    }
+
+    /// Moves the cursor to a coin's row in the active view, if it is shown.
+    fn select_coin(&mut self, coin: &str) -> bool {
+        let Some(row) = self
+            .items
+            .iter()
+            .filter(|c| self.is_row_visible(c))
+            .position(|c| c.coin == coin)
+        else {
+            return false;
+        };
+        self.state.select(Some(row));
+        self.scroll_state = self.scroll_state.position(row * ITEM_HEIGHT);
+        true
+    }
+
+    /// Moves the cursor to the configured `INITIAL_SELECTION` once what it
+    /// needs is there: favorites at startup, funding after the first batch
+    /// of updates.
+    fn apply_initial_selection(&mut self) {
+        let coin = match self.initial_selection {
+            None => return,
+            Some(InitialSelection::FirstRow) => None,
+            Some(InitialSelection::FirstFavorite) => self
+                .favorites
+                .iter()
+                .find(|f| {
+                    self.items
+                        .iter()
+                        .any(|c| &c.coin == *f && self.is_row_visible(c))
+                })
+                .cloned(),
+            Some(InitialSelection::HighestFunding) => {
+                if !self.items.iter().any(CoinData::has_data) {
+                    return;
+                }
+                self.items
+                    .iter()
+                    .filter(|c| self.is_row_visible(c) && c.has_data() && !c.is_funding_suspect())
+                    .max_by(|a, b| {
+                        let a = a.hourly_funding_value(self.smoothed).abs();
+                        let b = b.hourly_funding_value(self.smoothed).abs();
+                        a.total_cmp(&b)
+                    })
+                    .map(|c| c.coin.clone())
+            }
+        };
+        self.initial_selection = None;
+        if let Some(coin) = coin {
+            self.select_coin(&coin);
+        }
+    }
+
+    /// Number of rows currently rendered in the table.
+    fn row_count(&self) -> usize {
+        self.items.iter().filter(|c| self.is_row_visible(c)).count()
+    }
+
+    fn switch_tab(&mut self, forward: bool) {
+        let count = self.tabs.len();
+        if count < 2 {
+            return;
+        }
+
+        // Park the current view state on the tab being left
+        self.tabs[self.active_tab] = TabView {
+            state: self.state.clone(),
+            round: self.round,
+            sorted_column: self.sorted_column,
+        };
+
+        self.active_tab = if forward {
+            (self.active_tab + 1) % count
+        } else {
+            (self.active_tab + count - 1) % count
+        };
+
+        let view = self.tabs[self.active_tab].clone();
+        self.state = view.state;
+        self.round = view.round;
+        self.sorted_column = None;
+        if let Some(column) = view.sorted_column {
+            self.sort_by_column(column);
+        }
+        self.update_scrollbar_size();
+        let selected = self.state.selected().unwrap_or(0);
+        self.scroll_state = self.scroll_state.position(selected * ITEM_HEIGHT);
+    }
+
     fn next_row(&mut self) {
-        let i = match self.state.selected() {
-            Some(i) if i >= self.items.len() - 1 => 0,
-            Some(i) => i + 1,
-            None => 0,
+        let Some(i) = navigation::next_row(
+            self.state.selected(),
+            self.row_count(),
+            self.wrap_navigation,
+        ) else {
+            return;
         };
         self.state.select(Some(i));
         self.scroll_state = self.scroll_state.position(i * ITEM_HEIGHT);
     }
 
-    fn select_row(&mut self, ch: String) -> Result<()> {
-        let row = self
+    /// Selects the first visible coin matching the query and returns how
+    /// many visible coins match.
+    fn select_row(&mut self, ch: String) -> Result<usize> {
+        let matches: Vec<usize> = self
             .items
             .iter()
+            .filter(|c| self.is_row_visible(c))
             .enumerate()
-            .filter(|c| c.1.has_data())
-            .position(|c| c.1.coin.starts_with(&ch))
-            .ok_or_else(|| color_eyre::eyre::eyre!("No coin found starting with '{}'", ch))?;
+            .filter(|(_, c)| self.search_scope.matches(c, &ch))
+            .map(|(row, _)| row)
+            .collect();
+        let row = *matches.first().ok_or_else(|| {
+            color_eyre::eyre::eyre!(
+                "No coin matching \"{}\" in {}",
+                ch,
+                self.search_scope.label()
+            )
+        })?;
 
         self.state.select(Some(row));
         self.scroll_state = self.scroll_state.position(row * ITEM_HEIGHT);
-        Ok(())
+        Ok(matches.len())
+    }
+
+    /// Shows a transient message popup for `notice_duration`.
+    fn show_notice(&mut self, message: String) {
+        self.notice = Some((message, Instant::now()));
     }
 
     fn previous_row(&mut self) {
+        let Some(i) = navigation::previous_row(
+            self.state.selected(),
+            self.row_count(),
+            self.wrap_navigation,
+        ) else {
+            return;
+        };
+        self.state.select(Some(i));
+        self.scroll_state = self.scroll_state.position(i * ITEM_HEIGHT);
+    }
+
+    /// Advances the display-board slideshow by a page when due, wrapping to
+    /// the top after the last page.
+    fn auto_scroll(&mut self) {
+        if !self.auto_scroll || Instant::now() < self.auto_scroll_at {
+            return;
+        }
+        self.auto_scroll_at = Instant::now() + Duration::from_millis(AUTO_SCROLL_INTERVAL_MS);
+        let count = self.row_count();
+        if count == 0 {
+            return;
+        }
         let i = match self.state.selected() {
-            Some(0) => 0,
-            Some(i) => i - 1,
+            Some(i) if i + 1 >= count => 0,
+            Some(i) => (i + self.page_step()).min(count - 1),
             None => 0,
         };
         self.state.select(Some(i));
         self.scroll_state = self.scroll_state.position(i * ITEM_HEIGHT);
     }
 
+    fn toggle_auto_scroll(&mut self) {
+        self.auto_scroll = !self.auto_scroll;
+        self.auto_scroll_at = Instant::now() + Duration::from_millis(AUTO_SCROLL_INTERVAL_MS);
+        let message = if self.auto_scroll {
+            "Auto-scroll on"
+        } else {
+            "Auto-scroll off"
+        };
+        self.show_notice(message.to_string());
+    }
+
+    /// Moves the selection by one page of visible rows, clamping at the
+    /// ends rather than wrapping.
+    fn page_down(&mut self) {
+        let count = self.row_count();
+        if count == 0 {
+            return;
+        }
+        let i = self
+            .state
+            .selected()
+            .map_or(0, |i| (i + self.page_step()).min(count - 1));
+        self.state.select(Some(i));
+        self.scroll_state = self.scroll_state.position(i * ITEM_HEIGHT);
+    }
+
+    fn page_up(&mut self) {
+        let count = self.row_count();
+        if count == 0 {
+            return;
+        }
+        let i = self
+            .state
+            .selected()
+            .map_or(0, |i| i.saturating_sub(self.page_step()).min(count - 1));
+        self.state.select(Some(i));
+        self.scroll_state = self.scroll_state.position(i * ITEM_HEIGHT);
+    }
+
+    fn page_step(&self) -> usize {
+        self.page_rows.saturating_sub(PAGE_OVERLAP_ROWS).max(1)
+    }
+
     fn next_column(&mut self) {
-        self.state.select_next_column();
+        let last = self.columns().len() - 1;
+        let next = self
+            .state
+            .selected_column()
+            .map_or(0, |i| (i + 1).min(last));
+        self.state.select_column(Some(next));
     }
 
     fn previous_column(&mut self) {
@@ -232,51 +820,240 @@ impl TuiApp {
     }
 
     fn sort_collumn(&mut self) {
-        if let Some(selected_col) = self.state.selected_column() {
-            match selected_col {
-                0 => self.items.sort_by(|a, b| a.coin.cmp(&b.coin)),
-                1 => self.items.sort_by(|a, b| {
-                    b.funding
-                        .partial_cmp(&a.funding)
-                        .unwrap_or(std::cmp::Ordering::Equal)
-                }),
-                2 => {
-                    if !self.symbol {
-                        self.items.sort_by(|a, b| {
-                            b.open_interest
-                                .partial_cmp(&a.open_interest)
-                                .unwrap_or(std::cmp::Ordering::Equal)
-                        })
-                    } else {
-                        self.items.sort_by(|a, b| {
-                            (b.open_interest * b.oracle_price)
-                                .partial_cmp(&(a.open_interest * a.oracle_price))
-                                .unwrap_or(std::cmp::Ordering::Equal)
-                        })
-                    }
-                }
-                _ => {}
+        // An explicit sort ends the grace period
+        self.sort_grace_until = None;
+        if let Some(column) = self
+            .state
+            .selected_column()
+            .and_then(|i| self.columns().get(i).copied())
+        {
+            self.sort_by_column(column);
+        }
+    }
+
+    /// Numeric value a column sorts by, largest first. `None` for columns
+    /// without a numeric value or coins missing one; those sort last.
+    fn sort_value(&self, column: Column, c: &CoinData) -> Option<f64> {
+        match column {
+            Column::Funding if c.is_funding_suspect() => None,
+            Column::Funding => Some(c.hourly_funding_value(self.smoothed)),
+            Column::FundingPeriod(_) if c.is_funding_suspect() => None,
+            Column::FundingPeriod(_) => Some(c.hourly_funding_value(self.smoothed)),
+            Column::OpenInterest if self.symbol => c.open_interest_usd(),
+            Column::OpenInterest => Some(c.open_interest),
+            Column::VenueAge => c.venue_age_secs(),
+            Column::FundingRank => self.funding_ranks.get(&c.coin).copied(),
+            Column::Basis => c.basis_hourly(),
+            Column::Premium => c.premium(),
+            Column::FundingDelta => c.funding_delta(),
+            Column::BlendedFunding => c.blended_funding(),
+            Column::Countdown => c.funding_countdown().map(|(_, secs)| secs as f64),
+            Column::CarryScore => self.carry_score(c),
+            Column::Divergence => Divergence::for_coin(c, DIVERGENCE_MIN_SAMPLES)
+                .map(|d| d.magnitude(DIVERGENCE_RULE)),
+            Column::LastPrice => c.last_trade_price,
+            Column::VenueCount => (c.venue_count() > 0).then_some(c.venue_count() as f64),
+            Column::DailyChange => c.daily_change_pct,
+            Column::DailyYield => self.daily_yield(c),
+            Column::NetFunding if c.is_funding_suspect() => None,
+            Column::NetFunding => Some(c.hourly_funding_value(self.smoothed) - baseline_hourly()),
+            Column::Coin | Column::Exchange => None,
+        }
+    }
+
+    fn sort_by_column(&mut self, column: Column) {
+        if !column.is_sortable() {
+            return;
+        }
+
+        let followed = self
+            .follow_selection
+            .then(|| self.selected_coin().map(|c| c.coin.clone()))
+            .flatten();
+
+        let mut items = std::mem::take(&mut self.items);
+        if column == Column::Coin {
+            sorting::sort_by_name(&mut items);
+        } else {
+            sorting::sort_desc_by(&mut items, |c| self.sort_value(column, c));
+        }
+        self.items = items;
+        self.sorted_column = Some(column);
+        self.arrange_rows();
+
+        if let Some(coin) = followed {
+            self.select_coin(&coin);
+        }
+    }
+
+    /// Moves favorites to the top, keeping the rest in sort order. The sort
+    /// is stable, so in global mode favorites also keep the sort order.
+    fn place_favorites(&mut self) {
+        if self.favorites.is_empty() {
+            return;
+        }
+        let favorites = &self.favorites;
+        self.items.sort_by_key(|c| {
+            match (favorites.iter().position(|f| *f == c.coin), FAVORITES_SORT) {
+                (Some(i), FavoritesSort::Manual) => i,
+                (Some(_), FavoritesSort::Global) => 0,
+                (None, _) => usize::MAX,
             }
+        });
+    }
+
+    /// Orders rows for display: favorites first, then, when grouping is on,
+    /// by group. Both sorts are stable, so each group keeps the sort order.
+    fn arrange_rows(&mut self) {
+        self.place_favorites();
+        if let Some(group_by) = self.group_by {
+            group_by.sort_into_groups(&mut self.items, &self.favorites);
         }
     }
 
-    fn next_round(&mut self) {
-        self.round = match self.round {
-            FundingRateRound::Hourly => FundingRateRound::QuadriHourly,
-            FundingRateRound::QuadriHourly => FundingRateRound::OctaHourly,
-            FundingRateRound::OctaHourly => FundingRateRound::Daily,
-            FundingRateRound::Daily => FundingRateRound::Monthly,
-            FundingRateRound::Monthly => FundingRateRound::Annually,
-            FundingRateRound::Annually => FundingRateRound::Hourly,
+    /// Re-sorts after favorites change, keeping the cursor on its coin.
+    fn reorder_favorites(&mut self) {
+        let followed = self.selected_coin().map(|c| c.coin.clone());
+        match self.sorted_column {
+            Some(column) => self.sort_by_column(column),
+            None => self.arrange_rows(),
+        }
+        if let Some(coin) = followed {
+            self.select_coin(&coin);
+        }
+        if let Err(e) = save_favorites(&self.favorites) {
+            log_debug(format!("Failed to save favorites: {}", e));
+        }
+    }
+
+    fn toggle_favorite(&mut self) {
+        let Some(coin) = self.selected_coin().map(|c| c.coin.clone()) else {
+            return;
+        };
+        let message = match self.favorites.iter().position(|f| *f == coin) {
+            Some(i) => {
+                self.favorites.remove(i);
+                format!("Removed {} from favorites", coin)
+            }
+            None => {
+                self.favorites.push(coin.clone());
+                format!("Added {} to favorites", coin)
+            }
+        };
+        self.reorder_favorites();
+        self.show_notice(message);
+    }
+
+    /// Moves the selected favorite up (negative) or down the manual order.
+    fn move_favorite(&mut self, offset: isize) {
+        if FAVORITES_SORT != FavoritesSort::Manual {
+            self.show_notice("Favorites follow the sort column".to_string());
+            return;
+        }
+        let Some(coin) = self.selected_coin().map(|c| c.coin.clone()) else {
+            return;
+        };
+        let Some(i) = self.favorites.iter().position(|f| *f == coin) else {
+            return;
+        };
+        let Some(target) = i
+            .checked_add_signed(offset)
+            .filter(|&t| t < self.favorites.len())
+        else {
+            return;
+        };
+        self.favorites.swap(i, target);
+        self.reorder_favorites();
+    }
+
+    /// Re-applies the active sort so rows track live values.
+    fn resort(&mut self) {
+        if self.sort_grace_until.is_some() {
+            return;
+        }
+        match self.sorted_column {
+            Some(column) => self.sort_by_column(column),
+            None => self.arrange_rows(),
+        }
+    }
+
+    fn toggle_alerting_only(&mut self) {
+        self.alerting_only = !self.alerting_only;
+        self.refilter();
+    }
+
+    /// Shows the complement of the active filters, or restores them.
+    fn toggle_filter_inverted(&mut self) {
+        if !self.has_active_filter() {
+            self.show_notice("No filter to invert".to_string());
+            return;
+        }
+        self.filter_inverted = !self.filter_inverted;
+        self.refilter();
+    }
+
+    /// Keeps the cursor on the same coin after the filter changes, or moves
+    /// it to the top when that coin is filtered out.
+    fn refilter(&mut self) {
+        let followed = self.selected_coin().map(|c| c.coin.clone());
+        self.update_scrollbar_size();
+        if !followed.is_some_and(|coin| self.select_coin(&coin)) {
+            self.state.select(Some(0));
+            self.scroll_state = self.scroll_state.position(0);
+        }
+    }
+
+    fn toggle_grouping(&mut self) {
+        self.group_by = match self.group_by {
+            Some(_) => None,
+            None => Some(GROUP_BY),
         };
+        let followed = self.selected_coin().map(|c| c.coin.clone());
+        match self.sorted_column {
+            Some(column) => self.sort_by_column(column),
+            None => self.arrange_rows(),
+        }
+        if let Some(coin) = followed {
+            self.select_coin(&coin);
+        }
+    }
+
+    fn toggle_hide_pegged(&mut self) {
+        self.hide_pegged = !self.hide_pegged;
+        self.refilter();
+    }
+
+    fn toggle_follow_selection(&mut self) {
+        self.follow_selection = !self.follow_selection;
+    }
+
+    fn next_round(&mut self) {
+        self.round = self.round.next();
+    }
+
+    /// Swaps the single funding column for one column per configured
+    /// period, carrying the sort over to the column that replaces it.
+    fn toggle_multi_period(&mut self) {
+        if FUNDING_PERIODS.is_empty() {
+            self.show_notice("No funding periods configured".to_string());
+            return;
+        }
+        self.multi_period = !self.multi_period;
+        self.sorted_column = self
+            .sorted_column
+            .map(|column| column.for_period_mode(self.multi_period, FUNDING_PERIODS));
+    }
+
+    fn toggle_smoothed(&mut self) {
+        self.smoothed = !self.smoothed;
+    }
+
+    fn toggle_compound(&mut self) {
+        self.compound = !self.compound;
     }
 
     fn update_scrollbar_size(&mut self) {
-        let items_with_data = self
-            .items
-            .iter()
-            .filter(|c| c.has_data() && self.visible_coins.contains(&c.coin))
-            .count();
+        let items_with_data = self.row_count();
         self.scroll_state = self
             .scroll_state
             .content_length((items_with_data.saturating_sub(1)) * ITEM_HEIGHT);
@@ -286,81 +1063,789 @@ impl TuiApp {
         self.symbol = !self.symbol;
     }
 
+    fn toggle_oi_both_units(&mut self) {
+        self.oi_both_units = !self.oi_both_units;
+    }
+
+    /// Switches between abbreviated (K/M/B) and precise large numbers.
+    fn toggle_compact_numbers(&mut self) {
+        self.compact_numbers = !self.compact_numbers;
+    }
+
     fn toggle_popup(&mut self) {
         self.popup = !self.popup;
     }
 
-    pub fn run(
-        mut self,
-        mut terminal: DefaultTerminal,
-        mut rx: mpsc::UnboundedReceiver<(String, f64, f64, f64, u8)>,
-    ) -> Result<()> {
-        loop {
-            // Check for coin list updates
-            while let Ok(new_coins) = self.coin_list_rx.try_recv() {
-                self.update_coin_list(new_coins);
-            }
+    fn open_prompt(&mut self, prompt: Prompt) {
+        self.prompt = prompt;
+        self.popup_message.clear();
+        self.popup = true;
+    }
 
-            // Drain updates
-            while let Ok((coin, funding, oi, price, exchange)) = rx.try_recv() {
-                self.update_coin(&coin, funding, oi, price, exchange);
+    fn submit_prompt(&mut self) {
+        self.toggle_popup();
+        let input = std::mem::take(&mut self.popup_message);
+        match self.prompt {
+            Prompt::Search => {
+                self.state = TableState::default().with_selected(0);
+                let message = match self.select_row(input.clone()) {
+                    Ok(1) => format!("1 match for \"{}\"", input),
+                    Ok(n) => format!("{} matches for \"{}\"", n, input),
+                    Err(e) => e.to_string(),
+                };
+                self.show_notice(message);
             }
+            Prompt::Threshold => self.set_threshold(&input),
+            Prompt::LayoutName => self.save_current_layout(&input),
+        }
+    }
 
-            terminal.draw(|frame| self.draw(frame))?;
+    /// The current view as a layout named `name`.
+    fn current_layout(&self, name: String) -> SavedLayout {
+        let mut columns = self.columns();
+        // Blended funding and venue counts are only shown in combined mode
+        // but stay chosen
+        if self.show_blended && !columns.contains(&Column::BlendedFunding) {
+            columns.push(Column::BlendedFunding);
+        }
+        if self.show_venue_count && !columns.contains(&Column::VenueCount) {
+            columns.push(Column::VenueCount);
+        }
+        SavedLayout {
+            name,
+            columns,
+            sorted_column: self.sorted_column,
+            alerting_only: self.alerting_only,
+            filter_inverted: self.filter_inverted,
+            round: self.round,
+            color_index: self.color_index,
+            builtin: false,
+        }
+    }
 
-            if event::poll(Duration::from_millis(POLL_DURATION_MS))? {
-                // Drain ALL events, not just one
-                while event::poll(Duration::from_millis(0))? {
-                    match event::read()? {
-                        Event::Key(key) if key.kind == KeyEventKind::Press => {
-                            let shift = key.modifiers.contains(KeyModifiers::SHIFT);
-                            if !self.popup {
-                                match key.code {
-                                    KeyCode::Char('q') | KeyCode::Esc => return Ok(()),
-                                    KeyCode::Char('j') | KeyCode::Down => self.next_row(),
-                                    KeyCode::Char('k') | KeyCode::Up => self.previous_row(),
-                                    KeyCode::Char('l') | KeyCode::Right if shift => {
-                                        self.next_color()
-                                    }
-                                    KeyCode::Char('h') | KeyCode::Left if shift => {
-                                        self.previous_color()
-                                    }
-                                    KeyCode::Char('l') | KeyCode::Right => self.next_column(),
-                                    KeyCode::Char('h') | KeyCode::Left => self.previous_column(),
-                                    KeyCode::Char('r') => self.next_round(),
-                                    KeyCode::Char('t') => self.toggle_symbol(),
-                                    KeyCode::Char('s') => self.next_exchange(),
-                                    KeyCode::Enter => self.sort_collumn(),
-                                    KeyCode::Char('/') => {
-                                        // clear popup message
-                                        self.popup_message.clear();
-                                        self.toggle_popup()
-                                    }
-                                    _ => {}
-                                }
-                            } else {
+    fn apply_layout(&mut self, layout: &SavedLayout) {
+        let shows = |column| layout.columns.contains(&column);
+        self.show_venue_age = shows(Column::VenueAge);
+        self.show_funding_rank = shows(Column::FundingRank);
+        self.show_basis = shows(Column::Basis);
+        self.show_premium = shows(Column::Premium);
+        self.show_funding_delta = shows(Column::FundingDelta);
+        self.show_blended = shows(Column::BlendedFunding);
+        self.show_venue_count = shows(Column::VenueCount);
+        self.show_daily_change = shows(Column::DailyChange);
+        self.show_daily_yield = shows(Column::DailyYield);
+        self.show_countdown = shows(Column::Countdown);
+        self.show_carry = shows(Column::CarryScore);
+        self.show_divergence = shows(Column::Divergence);
+        self.show_last_price = shows(Column::LastPrice);
+        self.show_net = shows(Column::NetFunding);
+        self.multi_period = layout
+            .columns
+            .iter()
+            .any(|c| matches!(c, Column::FundingPeriod(_)));
+        self.state.select_column(Some(0));
+        self.column_offset = 0;
+
+        self.round = layout.round;
+        self.color_index = layout.color_index % PALETTES.len();
+        self.alerting_only = layout.alerting_only;
+        self.filter_inverted = layout.filter_inverted;
+        match layout.sorted_column {
+            Some(column) => self.sort_by_column(column),
+            None => self.sorted_column = None,
+        }
+        self.refilter();
+    }
+
+    /// Saves the current view as a named layout, replacing one with the
+    /// same name.
+    fn save_current_layout(&mut self, name: &str) {
+        let name = name.trim();
+        if name.is_empty() {
+            self.show_notice("Layout name can't be empty".to_string());
+            return;
+        }
+        let message = match save_layout(self.current_layout(name.to_string())) {
+            Ok(()) => format!("Saved layout \"{}\"", name),
+            Err(e) => format!("Failed to save layout \"{}\": {}", name, e),
+        };
+        log_debug(message.clone());
+        self.show_notice(message);
+    }
+
+    fn open_layout_picker(&mut self) {
+        self.layouts = load_layouts();
+        self.layout_state.select(Some(0));
+        self.layout_picker = true;
+    }
+
+    fn load_selected_layout(&mut self) {
+        let Some(layout) = self
+            .layout_state
+            .selected()
+            .and_then(|i| self.layouts.get(i))
+            .cloned()
+        else {
+            return;
+        };
+        self.layout_picker = false;
+        self.apply_layout(&layout);
+        self.show_notice(format!("Loaded layout \"{}\"", layout.name));
+    }
+
+    /// Deletes the selected layout from the picker. Built-in layouts stay.
+    fn delete_selected_layout(&mut self) {
+        let Some(layout) = self
+            .layout_state
+            .selected()
+            .and_then(|i| self.layouts.get(i))
+        else {
+            return;
+        };
+        if layout.builtin {
+            self.show_notice(format!("\"{}\" is built in", layout.name));
+            return;
+        }
+        let name = layout.name.clone();
+        if let Err(e) = delete_layout(&name) {
+            self.show_notice(format!("Failed to delete layout \"{}\": {}", name, e));
+            return;
+        }
+        self.layouts = load_layouts();
+        let last = self.layouts.len().saturating_sub(1);
+        self.layout_state
+            .select(self.layout_state.selected().map(|i| i.min(last)));
+    }
+
+    /// Sets the funding threshold from a value typed in the displayed unit
+    /// (percent over the current round) and saves it for later sessions.
+    fn set_threshold(&mut self, input: &str) {
+        let Ok(percent) = input.trim().trim_end_matches('%').parse::<f64>() else {
+            self.show_notice(format!("Invalid threshold \"{}\": enter a number", input));
+            return;
+        };
+        if !percent.is_finite() {
+            self.show_notice(format!("Invalid threshold \"{}\": enter a number", input));
+            return;
+        }
+
+        self.funding_threshold = deannualize(percent / 100.0, self.round, self.compound);
+        if let Err(e) = save_state(THRESHOLD_STATE_FILE, &self.funding_threshold.to_string()) {
+            log_debug(format!("Failed to save threshold: {:?}", e));
+        }
+        self.show_notice(format!(
+            "Threshold set to {}% {}",
+            percent,
+            self.round.label()
+        ));
+    }
+
+    fn toggle_color_mode(&mut self) {
+        self.color_mode = self.color_mode.toggled();
+    }
+
+    /// Color for an hourly funding rate under the active `ColorMode`.
+    fn funding_color(&self, funding: f64, baseline: f64) -> Color {
+        self.period_funding_color(funding, baseline, FundingRateRound::Hourly)
+    }
+
+    /// Color for an hourly funding rate shown over `round`, with the rate,
+    /// threshold and baseline all scaled to that period.
+    fn period_funding_color(&self, funding: f64, baseline: f64, round: FundingRateRound) -> Color {
+        let scale = |hourly| annualize(hourly, round, self.compound);
+        let (funding, threshold, baseline) = (
+            scale(funding),
+            scale(self.funding_threshold),
+            scale(baseline),
+        );
+        match self.color_mode {
+            ColorMode::Signed => self.colors.funding_rate_color(funding, threshold, baseline),
+            ColorMode::Magnitude => self
+                .colors
+                .funding_magnitude_color(funding, threshold, baseline),
+        }
+    }
+
+    fn toggle_histogram(&mut self) {
+        self.histogram = !self.histogram;
+    }
+
+    fn toggle_help(&mut self) {
+        self.help = !self.help;
+    }
+
+    fn toggle_detail_popup(&mut self) {
+        self.detail_popup = !self.detail_popup && self.selected_coin().is_some();
+    }
+
+    fn export_alerts(&mut self, json: bool) {
+        let message = match self.alerts.export(json) {
+            Ok(path) => format!(
+                "Exported {} alerts to {}",
+                self.alerts.len(),
+                path.display()
+            ),
+            Err(e) => format!("Failed to export alerts: {}", e),
+        };
+        log_debug(message.clone());
+        self.show_notice(message);
+    }
+
+    /// Writes the current exchange's coin universe to a timestamped JSON
+    /// file in the data directory. The venues are queried in the background
+    /// and the outcome arrives as a notice through `notice_rx`.
+    fn export_universe(&mut self) {
+        let exchange = self.get_exchange();
+        let path = data_file(&format!(
+            "hype_universe_{}.json",
+            Timezone::display().format_now("%Y%m%d_%H%M%S")
+        ));
+        let notice_tx = self.notice_tx.clone();
+        tokio::spawn(async move {
+            let message = match dump_universe(exchange).await {
+                Ok(json) => match tokio::fs::write(&path, json).await {
+                    Ok(()) => format!("Exported coin universe to {}", path.display()),
+                    Err(e) => format!("Failed to write coin universe: {}", e),
+                },
+                Err(e) => format!("Failed to fetch coin universe: {}", e),
+            };
+            log_debug(message.clone());
+            let _ = notice_tx.send(message);
+        });
+        self.show_notice("Fetching coin universe...".to_string());
+    }
+
+    /// Writes a snapshot of the app state for bug reports to a timestamped
+    /// JSON file in the data directory: exchange, settings, feed health,
+    /// coin counts, a sample of rows and the tail of the debug log.
+    fn dump_diagnostics(&self) -> Result<PathBuf> {
+        let venues: Vec<_> = VENUES
+            .iter()
+            .map(|&venue| {
+                let health = self.health.venue(venue);
+                json!({
+                    "venue": venue_name(venue),
+                    "connected": health.connected,
+                    "last_update_secs_ago": health.last_update.map(|t| t.elapsed().as_secs_f64()),
+                    "failed_subscriptions": health.failed_subscriptions,
+                })
+            })
+            .collect();
+        let sample: Vec<_> = self
+            .items
+            .iter()
+            .filter(|c| self.is_row_visible(c))
+            .take(20)
+            .map(|c| {
+                json!({
+                    "coin": c.coin,
+                    "native_symbol": c.native_symbol,
+                    "exchange": c.current_exchange,
+                    "funding": c.funding,
+                    "open_interest": c.open_interest,
+                    "oracle_price": c.oracle_price,
+                    "mark_price": c.mark_price,
+                    "last_trade_price": c.last_trade_price,
+                    "suspect": c.is_funding_suspect(),
+                    "venues": c.quotes.keys().map(|v| venue_short_name(*v)).collect::<Vec<_>>(),
+                })
+            })
+            .collect();
+        let log = std::fs::read_to_string(data_file(DEBUG_LOG_FILE)).unwrap_or_default();
+        let log_lines: Vec<&str> = log.lines().collect();
+        let log_tail = &log_lines[log_lines.len().saturating_sub(DIAGNOSTICS_LOG_LINES)..];
+
+        let snapshot = json!({
+            "version": env!("CARGO_PKG_VERSION"),
+            "taken_at": Timezone::display().format_now("%+"),
+            "exchange": self.get_exchange(),
+            "settings": {
+                "round": self.round.label(),
+                "compound": self.compound,
+                "smoothed": self.smoothed,
+                "funding_threshold": self.funding_threshold,
+                "default_funding_threshold": FUNDING_RATE_THRESHOLD,
+                "columns": self.columns().iter().map(|c| format!("{:?}", c)).collect::<Vec<_>>(),
+                "sorted_column": self.sorted_column.map(|c| format!("{:?}", c)),
+                "search_scope": format!("{:?}", self.search_scope),
+                "alerting_only": self.alerting_only,
+                "filter_inverted": self.filter_inverted,
+                "follow_selection": self.follow_selection,
+                "auto_scroll": self.auto_scroll,
+                "active_tab": self.active_tab,
+                "muted": self.muted.iter().collect::<Vec<_>>(),
+            },
+            "venues": venues,
+            "coins": {
+                "listed": self.all_coins.len(),
+                "visible": self.row_count(),
+                "with_data": self.items.iter().filter(|c| c.has_data()).count(),
+                "suspect": self.items.iter().filter(|c| c.is_funding_suspect()).count(),
+            },
+            "dropped_updates": self.dropped_updates,
+            "alerts_recorded": self.alerts.len(),
+            "sample": sample,
+            "log_tail": log_tail,
+        });
+
+        let path = data_file(&format!(
+            "hype_diagnostics_{}.json",
+            Timezone::display().format_now("%Y%m%d_%H%M%S")
+        ));
+        std::fs::write(&path, serde_json::to_string_pretty(&snapshot)?)?;
+        Ok(path)
+    }
+
+    fn write_diagnostics(&mut self) {
+        let message = match self.dump_diagnostics() {
+            Ok(path) => format!(
+                "Diagnostics written to {} (attach it to bug reports)",
+                path.display()
+            ),
+            Err(e) => format!("Failed to write diagnostics: {}", e),
+        };
+        log_debug(message.clone());
+        self.show_notice(message);
+    }
+
+    fn toggle_funding_rank(&mut self) {
+        self.show_funding_rank = !self.show_funding_rank;
+    }
+
+    /// Recomputes each coin's funding percentile (0-100) among all coins
+    /// with data, at most once per `FUNDING_RANK_REFRESH_MS`.
+    fn refresh_funding_ranks(&mut self) {
+        if self
+            .ranks_refreshed_at
+            .is_some_and(|t| t.elapsed() < Duration::from_millis(FUNDING_RANK_REFRESH_MS))
+        {
+            return;
+        }
+        self.ranks_refreshed_at = Some(Instant::now());
+
+        let mut fundings: Vec<(&str, f64)> = self
+            .items
+            .iter()
+            .filter(|c| c.has_data() && !c.is_funding_suspect())
+            .map(|c| (c.coin.as_str(), c.hourly_funding()))
+            .collect();
+        fundings.sort_by(|a, b| a.1.total_cmp(&b.1));
+
+        let last = fundings.len().saturating_sub(1).max(1) as f64;
+        self.funding_ranks = fundings
+            .iter()
+            .enumerate()
+            .map(|(i, (coin, _))| (coin.to_string(), i as f64 / last * 100.0))
+            .collect();
+    }
+
+    fn toggle_basis(&mut self) {
+        self.show_basis = !self.show_basis;
+    }
+
+    fn toggle_premium(&mut self) {
+        self.show_premium = !self.show_premium;
+    }
+
+    /// Mutes or unmutes alerts for the selected coin and saves the list.
+    fn toggle_mute(&mut self) {
+        let Some(coin) = self.selected_coin().map(|c| c.coin.clone()) else {
+            return;
+        };
+        let message = if self.muted.remove(&coin) {
+            format!("Unmuted {}", coin)
+        } else {
+            self.muted.insert(coin.clone());
+            format!("Muted alerts for {}", coin)
+        };
+        if let Err(e) = save_muted(&self.muted) {
+            log_debug(format!("Failed to save muted coins: {}", e));
+        }
+        self.show_notice(message);
+    }
+
+    fn toggle_blended(&mut self) {
+        self.show_blended = !self.show_blended;
+        if self.show_blended && self.get_exchange() != 3 {
+            self.show_notice("Blended funding shows in combined mode".to_string());
+        }
+    }
+
+    fn toggle_venue_count(&mut self) {
+        self.show_venue_count = !self.show_venue_count;
+        if self.show_venue_count && self.get_exchange() != 3 {
+            self.show_notice("Venue counts show in combined mode".to_string());
+        }
+    }
+
+    fn toggle_last_price(&mut self) {
+        self.show_last_price = !self.show_last_price;
+    }
+
+    fn toggle_carry(&mut self) {
+        self.show_carry = !self.show_carry;
+    }
+
+    /// Risk-adjusted funding attractiveness under `CARRY_WEIGHTS`.
+    fn carry_score(&self, c: &CoinData) -> Option<f64> {
+        CarryInputs::for_coin(c, self.smoothed).map(|inputs| inputs.score(&CARRY_WEIGHTS))
+    }
+
+    fn toggle_divergence(&mut self) {
+        self.show_divergence = !self.show_divergence;
+    }
+
+    fn toggle_countdown(&mut self) {
+        self.show_countdown = !self.show_countdown;
+    }
+
+    fn toggle_daily_yield(&mut self) {
+        self.show_daily_yield = !self.show_daily_yield;
+    }
+
+    /// Switches between funding shown against zero and net of
+    /// `BASELINE_APR`, which adds the net column.
+    fn toggle_net(&mut self) {
+        self.show_net = !self.show_net;
+    }
+
+    /// Hourly rate funding is colored against: the baseline in the net view,
+    /// zero otherwise.
+    fn color_baseline(&self) -> f64 {
+        if self.show_net {
+            baseline_hourly()
+        } else {
+            0.0
+        }
+    }
+
+    /// Dollars of funding per day on `YIELD_NOTIONAL_USD`, positive when
+    /// longs pay shorts.
+    fn daily_yield(&self, c: &CoinData) -> Option<f64> {
+        if !c.has_data() || c.is_funding_suspect() {
+            return None;
+        }
+        Some(c.hourly_funding_value(self.smoothed) * 24.0 * YIELD_NOTIONAL_USD)
+    }
+
+    fn toggle_daily_change(&mut self) {
+        self.show_daily_change = !self.show_daily_change;
+    }
+
+    fn toggle_trend(&mut self) {
+        self.show_trend = !self.show_trend;
+    }
+
+    fn toggle_market_index(&mut self) {
+        self.show_market_index = !self.show_market_index;
+    }
+
+    /// Copies the selected coin's venue symbol to the system clipboard.
+    fn copy_symbol(&mut self) {
+        let Some(coin) = self.selected_coin() else {
+            return;
+        };
+        let symbol = coin
+            .native_symbol
+            .clone()
+            .unwrap_or_else(|| coin.coin.clone());
+
+        let message = match self.copy_to_clipboard(&symbol) {
+            Ok(()) => format!("Copied {}", symbol),
+            Err(e) => e,
+        };
+        self.show_notice(message);
+    }
+
+    /// Puts `text` on the system clipboard, describing the failure if not.
+    fn copy_to_clipboard(&mut self, text: &str) -> std::result::Result<(), String> {
+        // The clipboard is kept open because on X11 the copied text only
+        // lives as long as its owner. Headless sessions have no clipboard.
+        if self.clipboard.is_none() {
+            match arboard::Clipboard::new() {
+                Ok(clipboard) => self.clipboard = Some(clipboard),
+                Err(e) => {
+                    log_debug(format!("Clipboard unavailable: {}", e));
+                    return Err(format!("Clipboard unavailable: {}", e));
+                }
+            }
+        }
+        match self
+            .clipboard
+            .as_mut()
+            .map(|clipboard| clipboard.set_text(text.to_string()))
+        {
+            Some(Ok(())) => Ok(()),
+            Some(Err(e)) => Err(format!("Copy failed: {}", e)),
+            None => Err("Clipboard unavailable".to_string()),
+        }
+    }
+
+    /// Opens the selected coin's trading page on the venue its row shows,
+    /// falling back to copying the URL where no browser can be launched.
+    fn open_trade_page(&mut self) {
+        let Some(coin) = self.selected_coin() else {
+            return;
+        };
+        let Some(venue) = coin.source_venue else {
+            self.show_notice(format!("No venue data for {} yet", coin.coin));
+            return;
+        };
+        let symbol = coin
+            .native_symbol
+            .clone()
+            .unwrap_or_else(|| coin.coin.clone());
+        let Some((_, template)) = TRADE_URL_TEMPLATES.iter().find(|(v, _)| *v == venue) else {
+            self.show_notice(format!("No trade URL for {}", venue_name(venue)));
+            return;
+        };
+        let url = trade_url(template, &symbol);
+
+        // A Linux session without a display has no browser to hand off to
+        let has_display = !cfg!(target_os = "linux")
+            || std::env::var_os("DISPLAY").is_some()
+            || std::env::var_os("WAYLAND_DISPLAY").is_some();
+        if has_display {
+            match open::that_detached(&url) {
+                Ok(()) => {
+                    self.show_notice(format!("Opened {}", url));
+                    return;
+                }
+                Err(e) => log_debug(format!("Failed to open {}: {}", url, e)),
+            }
+        }
+        let message = match self.copy_to_clipboard(&url) {
+            Ok(()) => format!("Copied {}", url),
+            Err(_) => url,
+        };
+        self.show_notice(message);
+    }
+
+    fn toggle_funding_delta(&mut self) {
+        self.show_funding_delta = !self.show_funding_delta;
+    }
+
+    fn toggle_venue_age(&mut self) {
+        self.show_venue_age = !self.show_venue_age;
+    }
+
+    fn toggle_repeat_header(&mut self) {
+        self.repeat_header = !self.repeat_header;
+    }
+
+    /// Sends any half-collected alert batch before the app exits.
+    pub async fn flush_alerts(&mut self) {
+        self.alert_dispatch.flush().await;
+    }
+
+    pub fn run(&mut self, mut terminal: DefaultTerminal, mut rx: UpdateReceiver) -> Result<()> {
+        let frame_budget = match MAX_FPS {
+            0 => Duration::ZERO,
+            fps => Duration::from_secs(1) / fps,
+        };
+        let mut last_draw: Option<Instant> = None;
+        let mut last_size = terminal.size().ok();
+        let mut resized = false;
+        loop {
+            // Check for coin list updates
+            while let Ok((generation, new_coins)) = self.coin_list_rx.try_recv() {
+                self.feed_generation = generation;
+                self.update_coin_list(new_coins);
+            }
+            while let Ok(message) = self.notice_rx.try_recv() {
+                self.show_notice(message);
+            }
+
+            // Drain updates
+            let mut updated = false;
+            while let Some(update) = rx.try_recv() {
+                self.update_coin(update);
+                updated = true;
+            }
+            if self
+                .sort_grace_until
+                .is_some_and(|until| Instant::now() >= until)
+            {
+                self.sort_grace_until = None;
+                updated = true;
+            }
+            if updated {
+                self.resort();
+                self.apply_initial_selection();
+            }
+            // Releases alerts held over quiet hours once the window ends
+            self.fire_alerts(Vec::new());
+            self.dropped_updates = rx.dropped();
+
+            self.auto_scroll();
+            // After a resize the whole screen is repainted right away; paging
+            // and the column window are derived from the new size while drawing
+            if std::mem::take(&mut resized) {
+                if CLEAR_ON_RESIZE {
+                    terminal.clear()?;
+                }
+                last_draw = None;
+            }
+            // Skip the redraw while inside the frame budget; the shortened
+            // poll below brings us back as soon as the budget is spent
+            let since_draw = last_draw.map(|at| at.elapsed());
+            let poll_timeout = match since_draw {
+                Some(elapsed) if elapsed < frame_budget => frame_budget - elapsed,
+                _ => {
+                    terminal.draw(|frame| self.draw(frame))?;
+                    last_draw = Some(Instant::now());
+                    Duration::from_millis(POLL_DURATION_MS)
+                }
+            };
+
+            if event::poll(poll_timeout.min(Duration::from_millis(POLL_DURATION_MS)))? {
+                // Drain ALL events, not just one
+                while event::poll(Duration::from_millis(0))? {
+                    match event::read()? {
+                        Event::Key(key) if key.kind == KeyEventKind::Press => {
+                            // Hand control back to the user for a while
+                            self.auto_scroll_at =
+                                Instant::now() + Duration::from_millis(AUTO_SCROLL_PAUSE_MS);
+                            let shift = key.modifiers.contains(KeyModifiers::SHIFT);
+                            if self.layout_picker {
+                                match key.code {
+                                    KeyCode::Char('L') | KeyCode::Char('q') | KeyCode::Esc => {
+                                        self.layout_picker = false
+                                    }
+                                    KeyCode::Char('j') | KeyCode::Down => {
+                                        self.layout_state.select_next()
+                                    }
+                                    KeyCode::Char('k') | KeyCode::Up => {
+                                        self.layout_state.select_previous()
+                                    }
+                                    KeyCode::Char('x') => self.delete_selected_layout(),
+                                    KeyCode::Enter => self.load_selected_layout(),
+                                    _ => {}
+                                }
+                            } else if self.help {
+                                if let KeyCode::Char('?') | KeyCode::Char('q') | KeyCode::Esc =
+                                    key.code
+                                {
+                                    self.toggle_help()
+                                }
+                            } else if self.histogram {
+                                if let KeyCode::Char('H') | KeyCode::Char('q') | KeyCode::Esc =
+                                    key.code
+                                {
+                                    self.toggle_histogram()
+                                }
+                            } else if self.detail_popup {
+                                match key.code {
+                                    KeyCode::Char('d') | KeyCode::Char('q') | KeyCode::Esc => {
+                                        self.toggle_detail_popup()
+                                    }
+                                    KeyCode::Char('j') | KeyCode::Down => self.next_row(),
+                                    KeyCode::Char('k') | KeyCode::Up => self.previous_row(),
+                                    KeyCode::PageDown => self.page_down(),
+                                    KeyCode::PageUp => self.page_up(),
+                                    _ => {}
+                                }
+                            } else if !self.popup {
                                 match key.code {
-                                    KeyCode::Char('/') => self.toggle_popup(),
+                                    KeyCode::Char('q') | KeyCode::Esc => return Ok(()),
+                                    KeyCode::Char('j') | KeyCode::Down => self.next_row(),
+                                    KeyCode::Char('k') | KeyCode::Up => self.previous_row(),
+                                    KeyCode::PageDown => self.page_down(),
+                                    KeyCode::PageUp => self.page_up(),
+                                    KeyCode::Char('l') | KeyCode::Right if shift => {
+                                        self.next_color()
+                                    }
+                                    KeyCode::Char('h') | KeyCode::Left if shift => {
+                                        self.previous_color()
+                                    }
+                                    KeyCode::Char('l') | KeyCode::Right => self.next_column(),
+                                    KeyCode::Char('h') | KeyCode::Left => self.previous_column(),
+                                    KeyCode::Char('r') => self.next_round(),
+                                    KeyCode::Char('F') => self.toggle_multi_period(),
+                                    KeyCode::Char('R') => self.refresh_coin_list(),
+                                    KeyCode::Char('y') => self.toggle_compound(),
+                                    KeyCode::Char('m') => self.toggle_smoothed(),
+                                    KeyCode::Char('t') => self.toggle_symbol(),
+                                    KeyCode::Char('T') => self.toggle_oi_both_units(),
+                                    KeyCode::Char('C') => self.toggle_compact_numbers(),
+                                    KeyCode::Char('s') => self.next_exchange(),
+                                    KeyCode::Char('d') => self.toggle_detail_popup(),
+                                    KeyCode::Char('H') => self.toggle_histogram(),
+                                    KeyCode::Char('G') => self.toggle_color_mode(),
+                                    KeyCode::Char('Z') => self.toggle_hide_pegged(),
+                                    KeyCode::Char('g') => self.toggle_countdown(),
+                                    KeyCode::Char('Y') => self.toggle_carry(),
+                                    KeyCode::Char('z') => self.toggle_divergence(),
+                                    KeyCode::Char('W') => self.toggle_last_price(),
+                                    KeyCode::Char('X') => self.toggle_grouping(),
+                                    KeyCode::Tab => self.switch_tab(true),
+                                    KeyCode::BackTab => self.switch_tab(false),
+                                    KeyCode::Char('p') => self.toggle_repeat_header(),
+                                    KeyCode::Char('a') => self.toggle_venue_age(),
+                                    KeyCode::Char('n') => self.toggle_funding_rank(),
+                                    KeyCode::Char('b') => self.toggle_basis(),
+                                    KeyCode::Char('P') => self.toggle_premium(),
+                                    KeyCode::Char('i') => self.toggle_market_index(),
+                                    KeyCode::Char('w') => self.toggle_trend(),
+                                    KeyCode::Char('%') => self.toggle_daily_change(),
+                                    KeyCode::Char('M') => self.toggle_mute(),
+                                    KeyCode::Char('*') => self.toggle_favorite(),
+                                    KeyCode::Char('K') => self.move_favorite(-1),
+                                    KeyCode::Char('J') => self.move_favorite(1),
+                                    KeyCode::Char('D') => self.toggle_daily_yield(),
+                                    KeyCode::Char('V') => self.toggle_venue_count(),
+                                    KeyCode::Char('N') => self.toggle_net(),
+                                    KeyCode::Char('A') => self.toggle_auto_scroll(),
+                                    KeyCode::Char('B') => self.toggle_blended(),
+                                    KeyCode::Char('v') => self.toggle_funding_delta(),
+                                    KeyCode::Char('c') => self.copy_symbol(),
+                                    KeyCode::Char('f') => self.toggle_follow_selection(),
+                                    KeyCode::Char('o') => self.toggle_alerting_only(),
+                                    KeyCode::Char('O') => self.toggle_filter_inverted(),
+                                    KeyCode::Char('L') => self.open_layout_picker(),
+                                    KeyCode::Char('S') => self.open_prompt(Prompt::LayoutName),
+                                    KeyCode::Char('e') => self.export_alerts(false),
+                                    KeyCode::Char('E') => self.export_alerts(true),
+                                    KeyCode::Char('U') => self.export_universe(),
+                                    KeyCode::Enter => self.sort_collumn(),
+                                    KeyCode::Char('/') => self.open_prompt(Prompt::Search),
+                                    KeyCode::Char('=') => self.open_prompt(Prompt::Threshold),
+                                    KeyCode::Char('?') => self.toggle_help(),
+                                    // Configurable keys come last so they can't
+                                    // shadow a built-in one
+                                    KeyCode::Char(c) if c == DIAGNOSTICS_KEY => {
+                                        self.write_diagnostics()
+                                    }
+                                    KeyCode::Char(c) if c == OPEN_TRADE_PAGE_KEY => {
+                                        self.open_trade_page()
+                                    }
+                                    _ => {}
+                                }
+                            } else {
+                                match key.code {
+                                    KeyCode::Char('/') | KeyCode::Esc => self.toggle_popup(),
+                                    KeyCode::Tab if self.prompt == Prompt::Search => {
+                                        self.search_scope = self.search_scope.next()
+                                    }
                                     KeyCode::Backspace => {
                                         let _ = self.popup_message.pop();
                                     }
                                     KeyCode::Char(c) => self.popup_message.push(c),
-                                    KeyCode::Enter => {
-                                        self.state = TableState::default().with_selected(0);
-                                        self.toggle_popup();
-                                        let result = self.select_row(self.popup_message.clone());
-                                        if result.is_err() {
-                                            self.error_popup_timer = Some(Instant::now());
-                                        }
-                                        self.popup_message.clear();
-                                    }
+                                    KeyCode::Enter => self.submit_prompt(),
                                     _ => {}
                                 }
                             }
                         }
+                        Event::Resize(width, height) => {
+                            let size = Size::new(width, height);
+                            if last_size != Some(size) {
+                                log_debug(format!("Terminal resized to {}x{}", width, height));
+                                last_size = Some(size);
+                                resized = true;
+                            }
+                        }
                         // Explicitly ignore mouse events and other event types
                         Event::Mouse(_)
-                        | Event::Resize(_, _)
                         | Event::FocusGained
                         | Event::FocusLost
                         | Event::Paste(_) => {}
@@ -372,22 +1857,64 @@ impl TuiApp {
     }
 
     fn draw(&mut self, frame: &mut Frame) {
-        let vertical = &Layout::vertical([Constraint::Min(5), Constraint::Length(4)]);
+        let tab_bar_height = if TABS.len() > 1 { 1 } else { 0 };
+        let vertical = &Layout::vertical([
+            Constraint::Length(tab_bar_height),
+            Constraint::Min(5),
+            Constraint::Length(4),
+        ]);
         let rects = vertical.split(frame.area());
         self.set_colors();
-        self.render_table(frame, rects[0]);
-        self.render_scrollbar(frame, rects[0]);
-        self.render_footer(frame, rects[1]);
+        if self.show_funding_rank {
+            self.refresh_funding_ranks();
+        }
+        self.render_tabs(frame, rects[0]);
+        self.render_table(frame, rects[1]);
+        self.render_scrollbar(frame, rects[1]);
+        self.render_footer(frame, rects[2]);
         if self.popup {
             self.render_popup(frame);
         }
-        if let Some(error_popup_timer) = self.error_popup_timer {
-            if error_popup_timer.elapsed().as_millis() > ERROR_POPUP_DURATION_MS.into() {
-                self.error_popup_timer = None;
-            } else {
-                self.render_popup_not_found(frame);
-            }
+        if self.detail_popup {
+            self.render_detail_popup(frame);
+        }
+        if self.histogram {
+            self.render_histogram(frame);
+        }
+        if self.help {
+            self.render_help(frame);
         }
+        if self.layout_picker {
+            self.render_layout_picker(frame);
+        }
+        if let Some((_, shown_at)) = &self.notice
+            && shown_at.elapsed() > self.notice_duration
+        {
+            self.notice = None;
+        }
+        if let Some((message, _)) = &self.notice {
+            self.render_notice(frame, message);
+        }
+    }
+
+    fn render_tabs(&self, frame: &mut Frame, area: Rect) {
+        if area.height == 0 {
+            return;
+        }
+        let tabs = Tabs::new(TABS.iter().map(|tab| tab.name))
+            .select(self.active_tab)
+            .style(
+                Style::new()
+                    .fg(self.colors.row_fg)
+                    .bg(self.colors.buffer_bg),
+            )
+            .highlight_style(
+                Style::new()
+                    .fg(self.colors.header_fg)
+                    .bg(self.colors.header_bg)
+                    .add_modifier(Modifier::BOLD),
+            );
+        frame.render_widget(tabs, area);
     }
 
     fn render_popup(&mut self, frame: &mut Frame) {
@@ -395,21 +1922,388 @@ impl TuiApp {
         let block = Block::bordered().title("Popup");
         let area = self.popup_area(area, 60, 20);
         frame.render_widget(Clear, area);
+        let title = match self.prompt {
+            Prompt::Search => format!(
+                "Search ({}) [Tab: change column]",
+                self.search_scope.label()
+            ),
+            Prompt::Threshold => format!("Funding threshold (% {})", self.round.label()),
+            Prompt::LayoutName => "Save layout as".to_string(),
+        };
         let paragraph = Paragraph::new(self.popup_message.as_str())
-            .block(Block::bordered().title("Search"))
+            .block(Block::bordered().title(title))
             .style(Style::default())
             .alignment(Alignment::Center);
         frame.render_widget(paragraph, area);
         frame.render_widget(block, area);
     }
 
-    fn render_popup_not_found(&mut self, frame: &mut Frame) {
+    fn render_detail_popup(&self, frame: &mut Frame) {
+        let Some(coin) = self.selected_coin() else {
+            return;
+        };
+        let format_rate = |hourly: f64| {
+            format!(
+                "{:.*}%",
+                precision_for(self.round),
+                annualize(hourly, self.round, self.compound) * 100.0
+            )
+        };
+
+        let mut lines = vec![
+            Line::from(format!("Funding ({}) by venue", self.round.label())).bold(),
+            Line::from(""),
+        ];
+        for venue in VENUES {
+            let line = match coin.quotes.get(&venue) {
+                Some(quote) if is_valid_price(quote.oracle_price) => format!(
+                    "{:<12} {:>14}   OI {:.2}   Px {:.4}",
+                    venue_name(venue),
+                    format_rate(quote.hourly_funding),
+                    quote.open_interest,
+                    quote.oracle_price
+                ),
+                Some(quote) => format!(
+                    "{:<12} {:>14}   OI {:.2}   Px N/A",
+                    venue_name(venue),
+                    format_rate(quote.hourly_funding),
+                    quote.open_interest
+                ),
+                None => format!("{:<12} {:>14}", venue_name(venue), "N/A"),
+            };
+            lines.push(Line::from(line));
+        }
+        let listed: Vec<&str> = coin.quotes.keys().map(|v| venue_short_name(*v)).collect();
+        lines.push(Line::from(format!(
+            "Listed on {} of {} venues: {}",
+            listed.len(),
+            VENUES.len(),
+            if listed.is_empty() {
+                "N/A".to_string()
+            } else {
+                listed.join(", ")
+            }
+        )));
+
+        if self.show_net && !coin.is_funding_suspect() {
+            let gross = coin.hourly_funding_value(self.smoothed);
+            lines.push(Line::from(format!(
+                "Gross {}   Net of {}% APR baseline {}",
+                format_rate(gross),
+                BASELINE_APR * 100.0,
+                format_rate(gross - baseline_hourly())
+            )));
+        }
+
+        // OI-weighted blend and the share each venue contributes
+        let weights = coin.venue_weights();
+        if let Some(blended) = coin.blended_funding() {
+            let shares: Vec<String> = weights
+                .iter()
+                .map(|(venue, _, share)| {
+                    format!("{} {:.0}%", venue_short_name(*venue), share * 100.0)
+                })
+                .collect();
+            lines.push(Line::from(format!(
+                "Blended (OI-weighted): {} ({})",
+                format_rate(blended),
+                shares.join(", ")
+            )));
+        }
+
+        // How each venue's native rate was turned into the values above, so
+        // combined-mode comparisons are read correctly
+        let shown = format!(
+            "{}{}",
+            self.round.label().to_lowercase(),
+            if self.compound { ", compounded" } else { "" }
+        );
+        for venue in coin.quotes.keys() {
+            lines.push(Line::from(format!(
+                "{} native: {}; shown: {}",
+                venue_name(*venue),
+                funding_convention(*venue).describe(),
+                shown
+            )));
+        }
+
+        let cheapest = coin
+            .quotes
+            .values()
+            .min_by(|a, b| a.hourly_funding.total_cmp(&b.hourly_funding));
+        let richest = coin
+            .quotes
+            .values()
+            .max_by(|a, b| a.hourly_funding.total_cmp(&b.hourly_funding));
+        lines.push(Line::from(""));
+        match (cheapest, richest) {
+            (Some(low), Some(high)) if low.venue != high.venue => {
+                lines.push(Line::from(format!(
+                    "Spread: {}",
+                    format_rate(high.hourly_funding - low.hourly_funding)
+                )));
+                lines.push(Line::from(format!(
+                    "Cheaper long: {}   Cheaper short: {}",
+                    venue_name(low.venue),
+                    venue_name(high.venue)
+                )));
+            }
+            _ => lines.push(Line::from("Spread: N/A (listed on one venue)")),
+        }
+
+        // Basis column (b): mark / oracle - 1, spread over BASIS_PERIOD_HOURS
+        // and scaled like funding so the two compare directly
+        if let (Some(basis), Some(hourly)) = (coin.basis(), coin.basis_hourly()) {
+            lines.push(Line::from(format!(
+                "Basis: {:.4}% (mark/oracle - 1), {} over {}h = {}",
+                basis * 100.0,
+                self.round.label(),
+                BASIS_PERIOD_HOURS,
+                format_rate(hourly)
+            )));
+        }
+
+        // Premium column (P): perp mark over spot, N/A on venues without a
+        // spot price separate from the oracle
+        let price_or_na = |price: Option<f64>| match price {
+            Some(price) if is_valid_price(price) => price.to_string(),
+            _ => "N/A".to_string(),
+        };
+        lines.push(Line::from(format!(
+            "Perp: {}   Last: {}   Spot: {}   Premium: {}",
+            price_or_na(Some(coin.mark_price)),
+            price_or_na(coin.last_trade_price),
+            price_or_na(coin.spot_price),
+            coin.premium()
+                .map(|premium| format!("{:+.4}%", premium * 100.0))
+                .unwrap_or_else(|| "N/A".to_string())
+        )));
+
+        if let Some(inputs) = CarryInputs::for_coin(coin, self.smoothed) {
+            lines.push(Line::from(format!(
+                "Carry score {:.2} = {} x |APR| {:.1}% + {} x log10(OI {} / $1M) - {} x 24h range {:.2}%",
+                inputs.score(&CARRY_WEIGHTS),
+                CARRY_WEIGHTS.funding,
+                inputs.funding_apr * 100.0,
+                CARRY_WEIGHTS.liquidity,
+                format_usd(inputs.open_interest_usd).trim_end(),
+                CARRY_WEIGHTS.volatility,
+                inputs.volatility * 100.0
+            )));
+        }
+
+        if let Some(divergence) = Divergence::for_coin(coin, DIVERGENCE_MIN_SAMPLES) {
+            let scaled = |hourly: f64| annualize(hourly, self.round, false) * 100.0;
+            lines.push(Line::from(format!(
+                "Average funding {:.*}% over {} samples   Deviation {:+.*}%{}{}",
+                precision_for(self.round),
+                scaled(divergence.average),
+                divergence.samples,
+                precision_for(self.round),
+                scaled(divergence.gap),
+                divergence
+                    .z_score
+                    .map(|z| format!(" ({:+.1}σ)", z))
+                    .unwrap_or_default(),
+                if divergence.is_significant(DIVERGENCE_RULE) {
+                    "   diverging"
+                } else {
+                    ""
+                }
+            )));
+        }
+
+        let age = |at: Option<std::time::Instant>| match at {
+            Some(at) => format!("{}s ago", at.elapsed().as_secs()),
+            None => "never".to_string(),
+        };
+        lines.push(Line::from(format!(
+            "Funding updated {}   Price updated {}{}",
+            age(coin.funding_updated_at),
+            age(coin.price_updated_at),
+            if coin.is_price_stale() {
+                format!(
+                    " (stale after {}s, USD values approximate)",
+                    PRICE_STALE_SECS
+                )
+            } else {
+                String::new()
+            }
+        )));
+
+        match (coin.daily_change_pct, coin.day_range) {
+            (Some(change), Some((low, high))) => lines.push(Line::from(format!(
+                "24h: {:+.2}%, range {} - {}",
+                change, low, high
+            ))),
+            (Some(change), None) => lines.push(Line::from(format!("24h: {:+.2}%", change))),
+            _ => {}
+        }
+
+        let area = if DETAIL_HISTORY {
+            self.popup_area(frame.area(), 70, 70)
+        } else {
+            self.popup_area(frame.area(), 70, 40)
+        };
+        if DETAIL_HISTORY && !coin.history.is_empty() {
+            lines.push(Line::from(""));
+            lines.push(Line::from("Recent funding (newest first)").bold());
+            // Whatever fits inside the borders below the lines above, leaving
+            // the bottom line to the countdown gauge
+            let rows = (area.height as usize).saturating_sub(lines.len() + 3);
+            for sample in coin.history.newest_first().take(rows) {
+                lines.push(Line::from(format!(
+                    "{}  {:<4} {:>14}",
+                    Timezone::display().format(sample.at, "%H:%M:%S"),
+                    venue_short_name(sample.venue),
+                    format_rate(sample.hourly_funding)
+                )));
+            }
+        }
+
+        frame.render_widget(Clear, area);
+        let paragraph = Paragraph::new(lines)
+            .block(Block::bordered().title(format!("{} details [d/Esc: close]", coin.coin)))
+            .style(
+                Style::new()
+                    .fg(self.colors.row_fg)
+                    .bg(self.colors.buffer_bg),
+            );
+        frame.render_widget(paragraph, area);
+
+        // Progress through the funding interval along the bottom line
+        if let Some((progress, secs)) = coin.funding_countdown() {
+            let inner = area.inner(Margin::new(1, 1));
+            let [gauge_area] = Layout::vertical([Constraint::Length(1)])
+                .flex(Flex::End)
+                .areas(inner);
+            let gauge = LineGauge::default()
+                .ratio(progress.clamp(0.0, 1.0))
+                .label(format!("Next funding in {}", format_countdown(secs)))
+                .filled_style(Style::new().fg(self.colors.selected_row_style_fg))
+                .unfilled_style(Style::new().fg(self.colors.alt_row_color));
+            frame.render_widget(gauge, gauge_area);
+        }
+    }
+
+    /// Shown coins bucketed by annualized funding, recomputed every frame.
+    /// The bin holding the alert threshold is labeled and colored.
+    fn render_histogram(&self, frame: &mut Frame) {
+        let to_apr = |hourly: f64| annualize(hourly, FundingRateRound::Annually, self.compound);
+        let counts = histogram::funding_bins(
+            self.items
+                .iter()
+                .filter(|c| self.is_row_visible(c) && c.has_data() && !c.is_funding_suspect())
+                .map(|c| to_apr(c.hourly_funding_value(self.smoothed))),
+        );
+        let threshold_bin = histogram::bin_of(to_apr(self.funding_threshold));
+        let last = counts.len() - 1;
+
+        let bars: Vec<Bar> = counts
+            .iter()
+            .enumerate()
+            .map(|(bin, count)| {
+                let edge = match bin {
+                    0 => format!("<{:+.0}%", histogram::bin_start(1) * 100.0),
+                    _ if bin == last => format!(">={:+.0}%", histogram::bin_start(bin) * 100.0),
+                    _ => format!("{:+.0}%", histogram::bin_start(bin) * 100.0),
+                };
+                let bar = Bar::default().value(*count);
+                if bin == threshold_bin {
+                    bar.label(Line::from(format!("{} threshold", edge)).bold())
+                        .style(Style::new().fg(THRESHOLD_FLASH_COLOR))
+                } else {
+                    bar.label(Line::from(edge))
+                }
+            })
+            .collect();
+
+        let area = self.popup_area(frame.area(), 60, 90);
+        let chart = BarChart::default()
+            .direction(Direction::Horizontal)
+            .bar_width(1)
+            .bar_gap(0)
+            .data(BarGroup::default().bars(&bars))
+            .block(Block::bordered().title("Funding APR histogram [H/Esc: close]"))
+            .style(
+                Style::new()
+                    .fg(self.colors.row_fg)
+                    .bg(self.colors.buffer_bg),
+            );
+        frame.render_widget(Clear, area);
+        frame.render_widget(chart, area);
+    }
+
+    /// Every key binding, split over two columns so the list fits.
+    fn render_help(&self, frame: &mut Frame) {
+        let bindings = help::key_help();
+        let half = bindings.len().div_ceil(2);
+        let cells = |(keys, action): &(String, &'static str)| {
+            [
+                Cell::from(keys.clone()).style(Style::new().bold()),
+                Cell::from(*action),
+            ]
+        };
+        let rows = (0..half).map(|i| {
+            let mut row: Vec<Cell> = cells(&bindings[i]).into();
+            if let Some(right) = bindings.get(i + half) {
+                row.extend(cells(right));
+            }
+            Row::new(row)
+        });
+        let area = self.popup_area(frame.area(), 80, 90);
+        let table = Table::new(
+            rows,
+            [
+                Constraint::Length(18),
+                Constraint::Fill(1),
+                Constraint::Length(18),
+                Constraint::Fill(1),
+            ],
+        )
+        .block(Block::bordered().title("Keys [?/Esc: close]"))
+        .style(
+            Style::new()
+                .fg(self.colors.row_fg)
+                .bg(self.colors.buffer_bg),
+        );
+        frame.render_widget(Clear, area);
+        frame.render_widget(table, area);
+    }
+
+    fn render_layout_picker(&mut self, frame: &mut Frame) {
+        let area = self.popup_area(frame.area(), 40, 40);
+        let items = self.layouts.iter().map(|layout| {
+            if layout.builtin {
+                format!("{} (built-in)", layout.name)
+            } else {
+                layout.name.clone()
+            }
+        });
+        let list = List::new(items)
+            .block(Block::bordered().title("Layouts [Enter: load, x: delete, Esc: close]"))
+            .style(
+                Style::new()
+                    .fg(self.colors.row_fg)
+                    .bg(self.colors.buffer_bg),
+            )
+            .highlight_style(
+                Style::new()
+                    .fg(self.colors.selected_row_style_fg)
+                    .reversed(),
+            );
+        frame.render_widget(Clear, area);
+        frame.render_stateful_widget(list, area, &mut self.layout_state);
+    }
+
+    fn render_notice(&self, frame: &mut Frame, message: &str) {
         let area = frame.area();
         let block = Block::bordered().title("Popup");
         let area = self.popup_area(area, 40, 20);
         frame.render_widget(Clear, area);
-        let paragraph = Paragraph::new("Not found")
-            .block(Block::bordered().title("Search"))
+        let paragraph = Paragraph::new(message)
+            .wrap(Wrap { trim: true })
+            .block(Block::bordered().title("Notice"))
             .style(Style::default())
             .alignment(Alignment::Center);
         frame.render_widget(paragraph, area);
@@ -424,43 +2318,486 @@ impl TuiApp {
         area
     }
 
+    /// Columns shown in the table, in display order.
+    fn columns(&self) -> Vec<Column> {
+        let mut columns = vec![Column::Coin];
+        columns.extend(Column::funding_columns(self.multi_period, FUNDING_PERIODS));
+        if self.show_net {
+            columns.push(Column::NetFunding);
+        }
+        columns.extend([Column::OpenInterest, Column::Exchange]);
+        if self.show_venue_age {
+            columns.push(Column::VenueAge);
+        }
+        if self.show_funding_rank {
+            columns.push(Column::FundingRank);
+        }
+        if self.show_last_price {
+            columns.push(Column::LastPrice);
+        }
+        if self.show_basis {
+            columns.push(Column::Basis);
+        }
+        if self.show_premium {
+            columns.push(Column::Premium);
+        }
+        if self.show_funding_delta {
+            columns.push(Column::FundingDelta);
+        }
+        // Blending only means something when several venues stream
+        if self.show_blended && self.get_exchange() == 3 {
+            columns.push(Column::BlendedFunding);
+        }
+        if self.show_venue_count && self.get_exchange() == 3 {
+            columns.push(Column::VenueCount);
+        }
+        if self.show_daily_change {
+            columns.push(Column::DailyChange);
+        }
+        if self.show_daily_yield {
+            columns.push(Column::DailyYield);
+        }
+        if self.show_countdown {
+            columns.push(Column::Countdown);
+        }
+        if self.show_carry {
+            columns.push(Column::CarryScore);
+        }
+        if self.show_divergence {
+            columns.push(Column::Divergence);
+        }
+        columns
+    }
+
+    /// Picks the columns that fit in `width`: the coin column pinned on the
+    /// left, then a run of the others starting at the horizontal offset. The
+    /// offset follows the selected column and scrolls back when there is
+    /// room, so narrowing or widening the terminal settles on its own.
+    fn column_window(&mut self, columns: &[Column], width: u16) -> Vec<usize> {
+        let pinned = columns[0].min_width();
+        // Column spacing, plus a divider and its own spacing when enabled
+        let gap = if COLUMN_SEPARATORS { 3 } else { 1 };
+        let fits = |first: usize, end: usize| {
+            let scrolled: u16 = columns[first..end]
+                .iter()
+                .map(|c| c.min_width() + gap)
+                .sum();
+            pinned + scrolled <= width
+        };
+
+        let mut offset = self.column_offset.min(columns.len().saturating_sub(2));
+        if let Some(selected) = self
+            .state
+            .selected_column()
+            .filter(|&c| c > 0 && c < columns.len())
+        {
+            offset = offset.min(selected - 1);
+            while offset + 1 < selected && !fits(offset + 1, selected + 1) {
+                offset += 1;
+            }
+        }
+        while offset > 0 && fits(offset, columns.len()) {
+            offset -= 1;
+        }
+        self.column_offset = offset;
+
+        let mut window = vec![0];
+        for i in (offset + 1)..columns.len() {
+            if window.len() > 1 && !fits(offset + 1, i + 1) {
+                break;
+            }
+            window.push(i);
+        }
+        window
+    }
+
+    fn column_label(&self, column: Column) -> String {
+        match column {
+            Column::Coin => "Coin".to_string(),
+            Column::Funding => {
+                let label = match (self.round, self.compound) {
+                    (FundingRateRound::Annually, false) => {
+                        "Funding Rate (Annually, APR)".to_string()
+                    }
+                    (FundingRateRound::Annually, true) => {
+                        "Funding Rate (Annually, APY)".to_string()
+                    }
+                    (round, _) => format!("Funding Rate ({})", round.label()),
+                };
+                if self.smoothed {
+                    format!("{} EMA", label)
+                } else {
+                    label
+                }
+            }
+            Column::FundingPeriod(round) => {
+                let label = match (round, self.compound) {
+                    (FundingRateRound::Annually, false) => "Funding (APR)".to_string(),
+                    (FundingRateRound::Annually, true) => "Funding (APY)".to_string(),
+                    (round, _) => format!("Funding ({})", round.label()),
+                };
+                if self.smoothed {
+                    format!("{} EMA", label)
+                } else {
+                    label
+                }
+            }
+            Column::OpenInterest => "Open Interest".to_string(),
+            Column::Exchange => "Exchange".to_string(),
+            Column::VenueAge => "Round age".to_string(),
+            Column::FundingRank => "Rank".to_string(),
+            Column::Basis => format!("Basis ({})", self.round.label()),
+            Column::Premium => "Premium".to_string(),
+            Column::FundingDelta => format!("Pred - Real ({})", self.round.label()),
+            Column::BlendedFunding => format!("Blended ({})", self.round.label()),
+            Column::VenueCount => "Venues".to_string(),
+            Column::Countdown => "Next funding".to_string(),
+            Column::CarryScore => "Carry".to_string(),
+            Column::Divergence => match DIVERGENCE_RULE {
+                DivergenceRule::ZScore(_) => "vs Avg (σ)".to_string(),
+                DivergenceRule::Gap(_) => format!("vs Avg ({})", self.round.label()),
+            },
+            Column::LastPrice => "Last".to_string(),
+            Column::DailyChange => "24h %".to_string(),
+            Column::DailyYield => format!("$/day per ${}", YIELD_NOTIONAL_USD),
+            Column::NetFunding => format!(
+                "Net of {}% APR ({})",
+                BASELINE_APR * 100.0,
+                self.round.label()
+            ),
+        }
+    }
+
+    fn render_cell(&self, column: Column, c: &CoinData) -> Cell<'static> {
+        let (text, style) = self.cell_text(column, c);
+        let mut line = aligned(column, text);
+        let trend_column = Column::Funding.for_period_mode(self.multi_period, FUNDING_PERIODS);
+        if column == trend_column && self.show_trend {
+            // Blank when unknown so values stay aligned
+            let trend = c.source_venue.and_then(|venue| {
+                c.history.trend(
+                    venue,
+                    Duration::from_secs(TREND_LOOKBACK_SECS),
+                    TREND_DEAD_BAND,
+                )
+            });
+            let arrow = match trend {
+                Some(trend) => Span::styled(
+                    format!(" {}", trend.arrow()),
+                    self.colors.trend_color(trend),
+                ),
+                None => Span::raw("  "),
+            };
+            line.push_span(arrow);
+        }
+        Cell::from(line).style(style)
+    }
+
+    /// Funding scaled to `round`, flashing on change and marked when suspect.
+    fn funding_cell(&self, c: &CoinData, round: FundingRateRound, color: Color) -> (String, Style) {
+        let mut funding_style = Style::new().fg(color);
+        if c.funding_changed_at
+            .is_some_and(|t| t.elapsed() < self.flash_duration)
+        {
+            funding_style = funding_style
+                .bg(self.colors.flash_bg)
+                .add_modifier(Modifier::BOLD);
+        }
+
+        let funding_display =
+            annualize(c.hourly_funding_value(self.smoothed), round, self.compound) * 100.0;
+        let precision = precision_for(round);
+        if c.is_funding_suspect() {
+            return (
+                format!("{:.*}%?", precision, funding_display),
+                funding_style.fg(ratatui::style::Color::Yellow),
+            );
+        }
+        // Trailing space keeps the decimal point in line with suspect values
+        (
+            format!("{:.*}% ", precision, funding_display),
+            funding_style,
+        )
+    }
+
+    fn cell_text(&self, column: Column, c: &CoinData) -> (String, Style) {
+        match column {
+            Column::Coin => {
+                let name = display_symbol(&c.coin);
+                let name = if SHOW_MUTED_MARKER && self.muted.contains(&c.coin) {
+                    format!("{} 🔕", name)
+                } else {
+                    name
+                };
+                let name = if self.favorites.contains(&c.coin) {
+                    format!("★ {}", name)
+                } else {
+                    name
+                };
+                match position_for(POSITIONS, &c.coin) {
+                    Some(position) if c.has_data() => (
+                        name,
+                        self.colors.position_style(
+                            position.rate_paid(c.hourly_funding_value(self.smoothed)),
+                        ),
+                    ),
+                    Some(_) => (name, Style::new().add_modifier(Modifier::BOLD)),
+                    None => (name, Style::new()),
+                }
+            }
+            Column::Funding => self.funding_cell(
+                c,
+                self.round,
+                self.funding_color(c.hourly_funding_value(self.smoothed), self.color_baseline()),
+            ),
+            Column::FundingPeriod(round) => self.funding_cell(
+                c,
+                round,
+                self.period_funding_color(
+                    c.hourly_funding_value(self.smoothed),
+                    self.color_baseline(),
+                    round,
+                ),
+            ),
+            Column::OpenInterest => {
+                let open_interest_display = if self.oi_both_units {
+                    let amount = if self.compact_numbers {
+                        format_compact(c.open_interest).trim_end().to_string()
+                    } else {
+                        format_amount(c.open_interest)
+                    };
+                    let native = format!("{} {}", amount, display_symbol(&c.coin));
+                    // Without a usable price only the native amount is known
+                    match c.open_interest_usd() {
+                        Some(oi_usd) => format!("{} ({})", native, format_usd(oi_usd).trim_end()),
+                        None => native,
+                    }
+                } else if self.symbol {
+                    let Some(oi_usd) = c.open_interest_usd() else {
+                        return ("N/A".to_string(), Style::new());
+                    };
+                    format_usd(oi_usd)
+                } else if self.compact_numbers {
+                    format!(
+                        "{} {}",
+                        format_compact(c.open_interest).trim_end(),
+                        display_symbol(&c.coin)
+                    )
+                } else {
+                    format!("{} {}", c.open_interest, display_symbol(&c.coin))
+                };
+                // USD values from an old price are flagged, not trusted
+                let shows_usd = self.oi_both_units || self.symbol;
+                if shows_usd && c.is_price_stale() && c.open_interest_usd().is_some() {
+                    return (
+                        format!("~{}", open_interest_display),
+                        Style::new().add_modifier(Modifier::DIM),
+                    );
+                }
+                (open_interest_display, Style::new())
+            }
+            Column::Exchange => {
+                let (exchange_display, exchange_color) = match c.current_exchange {
+                    1 => ("HL", ratatui::style::Color::Green),
+                    2 => ("LT", ratatui::style::Color::Yellow),
+                    3 => ("BOTH", ratatui::style::Color::Cyan),
+                    _ => ("?", ratatui::style::Color::Gray),
+                };
+                // Combined rows name the venue their values came from
+                let exchange_display = match c.source_venue {
+                    Some(venue) if c.current_exchange == 3 => {
+                        format!("{}:{}", exchange_display, venue_short_name(venue))
+                    }
+                    _ => exchange_display.to_string(),
+                };
+                (exchange_display, Style::new().fg(exchange_color))
+            }
+            Column::VenueAge => match (c.venue_age_secs(), c.source_venue) {
+                (Some(age), Some(venue)) => {
+                    let interval_secs = funding_convention(venue).interval_hours * 3600.0;
+                    let secs = age as u64;
+                    (
+                        format!("{}:{:02}", secs / 60, secs % 60),
+                        Style::new().fg(self.colors.venue_age_color(age, interval_secs)),
+                    )
+                }
+                _ => ("N/A".to_string(), Style::new()),
+            },
+            Column::FundingRank => match self.funding_ranks.get(&c.coin) {
+                Some(rank) => (
+                    format!("{:.0}", rank),
+                    Style::new().fg(self.colors.funding_rank_color(*rank)),
+                ),
+                None => ("N/A".to_string(), Style::new()),
+            },
+            Column::Basis => match c.basis_hourly() {
+                Some(basis) => (
+                    format!(
+                        "{:.*}%",
+                        precision_for(self.round),
+                        annualize(basis, self.round, self.compound) * 100.0
+                    ),
+                    Style::new(),
+                ),
+                None => ("N/A".to_string(), Style::new()),
+            },
+            Column::Premium => match c.premium() {
+                Some(premium) => (
+                    format!("{:+.3}%", premium * 100.0),
+                    Style::new().fg(self.colors.sign_color(premium)),
+                ),
+                None => ("N/A".to_string(), Style::new()),
+            },
+            // Green when trades print above the oracle, red below
+            Column::LastPrice => match c.last_trade_price {
+                Some(last) if is_valid_price(c.oracle_price) => (
+                    last.to_string(),
+                    Style::new().fg(self.colors.sign_color(last - c.oracle_price)),
+                ),
+                Some(last) => (last.to_string(), Style::new()),
+                None => ("N/A".to_string(), Style::new()),
+            },
+            Column::Divergence => match Divergence::for_coin(c, DIVERGENCE_MIN_SAMPLES) {
+                Some(divergence) => {
+                    let text = divergence_text(&divergence, DIVERGENCE_RULE, self.round);
+                    let style = if divergence.is_significant(DIVERGENCE_RULE) {
+                        Style::new()
+                            .fg(self.colors.sign_color(divergence.gap))
+                            .add_modifier(Modifier::BOLD | Modifier::REVERSED)
+                    } else {
+                        Style::new().add_modifier(Modifier::DIM)
+                    };
+                    (text, style)
+                }
+                None => ("N/A".to_string(), Style::new()),
+            },
+            Column::CarryScore => match self.carry_score(c) {
+                Some(score) => (format!("{:.2}", score), Style::new()),
+                None => ("N/A".to_string(), Style::new()),
+            },
+            Column::Countdown => match c.funding_countdown() {
+                Some((progress, secs)) => (
+                    format!(
+                        "{} {}",
+                        progress_bar(progress, COUNTDOWN_BAR_WIDTH),
+                        format_countdown(secs)
+                    ),
+                    Style::new(),
+                ),
+                None => ("N/A".to_string(), Style::new()),
+            },
+            Column::VenueCount => match c.venue_count() {
+                0 => ("N/A".to_string(), Style::new()),
+                count => (count.to_string(), Style::new()),
+            },
+            Column::BlendedFunding => match c.blended_funding() {
+                Some(blended) => (
+                    format!(
+                        "{:.*}%",
+                        precision_for(self.round),
+                        annualize(blended, self.round, self.compound) * 100.0
+                    ),
+                    Style::new().fg(self.funding_color(blended, self.color_baseline())),
+                ),
+                None => ("N/A".to_string(), Style::new()),
+            },
+            Column::FundingDelta => match c.funding_delta() {
+                Some(delta) => (
+                    format!(
+                        "{:+.*}%",
+                        precision_for(self.round),
+                        annualize(delta, self.round, false) * 100.0
+                    ),
+                    self.colors
+                        .funding_delta_style(delta, self.funding_threshold),
+                ),
+                None => ("N/A".to_string(), Style::new()),
+            },
+            Column::DailyYield => match self.daily_yield(c) {
+                Some(dollars) => (
+                    format!("{:+.2}", dollars),
+                    Style::new().fg(self.colors.sign_color(dollars)),
+                ),
+                None => ("N/A".to_string(), Style::new()),
+            },
+            Column::DailyChange => match c.daily_change_pct {
+                Some(change) => (
+                    format!("{:+.2}%", change),
+                    Style::new().fg(self.colors.sign_color(change)),
+                ),
+                None => ("N/A".to_string(), Style::new()),
+            },
+            Column::NetFunding => {
+                if c.is_funding_suspect() {
+                    return ("N/A".to_string(), Style::new());
+                }
+                let hourly = c.hourly_funding_value(self.smoothed);
+                (
+                    format!(
+                        "{:+.*}%",
+                        precision_for(self.round),
+                        annualize(hourly - baseline_hourly(), self.round, false) * 100.0
+                    ),
+                    Style::new().fg(self.funding_color(hourly, baseline_hourly())),
+                )
+            }
+        }
+    }
+
     fn render_table(&mut self, frame: &mut Frame, area: Rect) {
         let header_style = Style::default()
             .fg(self.colors.header_fg)
             .bg(self.colors.header_bg);
-        let selected_row_style = Style::default()
-            .add_modifier(Modifier::REVERSED)
-            .fg(self.colors.selected_row_style_fg);
-        let selected_col_style = Style::default().fg(self.colors.selected_column_style_fg);
-        let selected_cell_style = Style::default()
-            .add_modifier(Modifier::REVERSED)
-            .fg(self.colors.selected_cell_style_fg);
-
-        let header_funding_rate_display = match self.round {
-            FundingRateRound::Hourly => "Funding Rate (Hourly)",
-            FundingRateRound::QuadriHourly => "Funding Rate (4-Hourly)",
-            FundingRateRound::OctaHourly => "Funding Rate (8-Hourly)",
-            FundingRateRound::Daily => "Funding Rate (Daily)",
-            FundingRateRound::Monthly => "Funding Rate (Monthly)",
-            FundingRateRound::Annually => "Funding Rate (Annually)",
-        };
-
-        let header: Row<'_> = [
-            "Coin",
-            header_funding_rate_display,
-            "Open Interest",
-            "Exchange",
-        ]
-        .into_iter()
-        .map(Cell::from)
-        .collect::<Row>()
-        .style(header_style);
-
-        let rows = self
+        let selected_row_style = self
+            .colors
+            .highlight_style(SELECTION_STYLE.row, self.colors.selected_row_style_fg);
+        let selected_col_style = self
+            .colors
+            .highlight_style(SELECTION_STYLE.column, self.colors.selected_column_style_fg);
+        let selected_cell_style = self
+            .colors
+            .highlight_style(SELECTION_STYLE.cell, self.colors.selected_cell_style_fg);
+
+        let all_columns = self.columns();
+        let window = self.column_window(&all_columns, area.width);
+        let columns: Vec<Column> = window.iter().map(|&i| all_columns[i]).collect();
+        let constraints = separated(
+            columns.iter().map(Column::constraint),
+            Constraint::Length(1),
+        );
+        let divider = Cell::from("│");
+        let mut header_labels: Vec<String> =
+            columns.iter().map(|c| self.column_label(*c)).collect();
+
+        // Arrows mark columns scrolled off either side
+        if window.get(1).is_some_and(|&i| i > 1) {
+            header_labels[1] = format!("◀ {}", header_labels[1]);
+        }
+        if window.last().is_some_and(|&i| i + 1 < all_columns.len()) {
+            let last = header_labels.len() - 1;
+            header_labels[last] = format!("{} ▶", header_labels[last]);
+        }
+
+        let header_cells = separated(
+            header_labels
+                .iter()
+                .zip(&columns)
+                .map(|(label, column)| Cell::from(aligned(*column, label.clone()))),
+            divider.clone(),
+        );
+        let header = Row::new(header_cells.clone()).style(header_style);
+
+        let repeated_header = Row::new(header_cells).style(
+            Style::default()
+                .fg(self.colors.header_fg)
+                .add_modifier(Modifier::DIM | Modifier::UNDERLINED),
+        );
+
+        let rows_iter = self
             .items
             .iter()
             .enumerate()
-            .filter(|(_, c)| c.has_data() && self.visible_coins.contains(&c.coin))
+            .filter(|(_, c)| self.is_row_visible(c))
             .map(|(i, c)| {
                 let bg = if i % 2 == 0 {
                     self.colors.normal_row_color
@@ -468,89 +2805,141 @@ impl TuiApp {
                     self.colors.alt_row_color
                 };
 
-                let funding_color = self.colors.funding_rate_color(c.funding);
-
-                let mut funding_display = c.funding;
-                let open_interest_display: String;
+                let cells = separated(
+                    columns.iter().map(|column| {
+                        let cell = self.render_cell(*column, c);
+                        match self.sorted_column {
+                            Some(sorted) if self.dim_unsorted_columns && sorted != *column => {
+                                cell.dim()
+                            }
+                            _ => cell,
+                        }
+                    }),
+                    divider.clone(),
+                );
 
-                match self.round {
-                    FundingRateRound::Hourly => {}
-                    FundingRateRound::QuadriHourly => {
-                        funding_display = c.funding * 4.0;
-                    }
-                    FundingRateRound::OctaHourly => {
-                        funding_display = c.funding * 8.0;
-                    }
-                    FundingRateRound::Daily => {
-                        funding_display = c.funding * 24.0;
-                    }
-                    FundingRateRound::Monthly => {
-                        funding_display = c.funding * 24.0 * 30.0;
+                // Rows keep their last values while their venue reconnects,
+                // dimmed until fresh updates arrive
+                let mut style = Style::new().fg(self.colors.row_fg).bg(bg);
+                if c.is_stale(|venue| self.health.venue(venue).connected) {
+                    style = style.add_modifier(Modifier::DIM);
+                }
+                if let Some(crossed) = self.crossed_at.get(&c.coin) {
+                    let flash = Duration::from_millis(THRESHOLD_FLASH_DURATION_MS);
+                    let elapsed = crossed.elapsed();
+                    if elapsed < flash {
+                        style = style.bg(THRESHOLD_FLASH_COLOR);
                     }
-                    FundingRateRound::Annually => {
-                        funding_display = c.funding * 24.0 * 365.0;
+                    if elapsed < flash / 2 {
+                        style = style.add_modifier(Modifier::BOLD);
                     }
                 }
+                (c, Row::new(cells).style(style))
+            });
 
-                if self.symbol {
-                    let oi_usd = c.open_interest * c.oracle_price;
-                    if oi_usd >= 1_000_000_000.0 {
-                        open_interest_display = format!("${:.2}B", oi_usd / 1_000_000_000.0);
-                    } else if oi_usd >= 1_000_000.0 {
-                        open_interest_display = format!("${:.2}M", oi_usd / 1_000_000.0);
-                    } else if oi_usd >= 1_000.0 {
-                        open_interest_display = format!("${:.2}K", oi_usd / 1_000.0);
-                    } else {
-                        open_interest_display = format!("${:.2}", oi_usd);
-                    }
-                } else {
-                    open_interest_display = format!("{} {}", c.open_interest, c.coin);
+        let groups = match self.group_by {
+            Some(group_by) => group_by.summarize(
+                self.items.iter().filter(|c| self.is_row_visible(c)),
+                &self.favorites,
+                self.smoothed,
+            ),
+            None => HashMap::new(),
+        };
+        let group_style = Style::default()
+            .fg(self.colors.header_fg)
+            .add_modifier(Modifier::BOLD | Modifier::UNDERLINED);
+        let group_row = |key: usize| {
+            let group = &groups[&key];
+            let cells = separated(
+                columns
+                    .iter()
+                    .map(|column| match (column, group.average_funding()) {
+                        (Column::Coin, _) => {
+                            Cell::from(format!("{} ({})", group.label, group.count))
+                        }
+                        (Column::Funding | Column::FundingPeriod(_), Some(hourly)) => {
+                            let round = match column {
+                                Column::FundingPeriod(round) => *round,
+                                _ => self.round,
+                            };
+                            let average = annualize(hourly, round, self.compound) * 100.0;
+                            Cell::from(aligned(
+                                *column,
+                                format!("{:.*}% ", precision_for(round), average),
+                            ))
+                        }
+                        _ => Cell::from(""),
+                    }),
+                divider.clone(),
+            );
+            Row::new(cells).style(group_style)
+        };
+
+        // Repeated headers and group separators are inserted between data
+        // rows, so the stored selection (which indexes data rows only) is
+        // translated into a separate state for rendering and the resulting
+        // offset copied back.
+        let mut rows: Vec<Row> = Vec::new();
+        let mut display_rows: Vec<usize> = Vec::new();
+        let mut current_group = None;
+        for (i, (c, row)) in rows_iter.enumerate() {
+            if self.repeat_header
+                && REPEAT_HEADER_INTERVAL > 0
+                && i > 0
+                && i % REPEAT_HEADER_INTERVAL == 0
+            {
+                rows.push(repeated_header.clone());
+            }
+            if let Some(group_by) = self.group_by {
+                let (key, _) = group_by.group_of(c, &self.favorites);
+                if current_group != Some(key) {
+                    current_group = Some(key);
+                    rows.push(group_row(key));
                 }
+            }
+            display_rows.push(rows.len());
+            rows.push(row);
+        }
 
-                let (exchange_display, exchange_color) = match c.current_exchange {
-                    1 => ("HL", ratatui::style::Color::Green),
-                    2 => ("LT", ratatui::style::Color::Yellow),
-                    3 => ("BOTH", ratatui::style::Color::Cyan),
-                    _ => ("?", ratatui::style::Color::Gray),
-                };
+        let mut render_state = self.state.clone();
+        render_state.select(
+            self.state
+                .selected()
+                .and_then(|i| display_rows.get(i).copied()),
+        );
+        render_state.select_column(
+            self.state
+                .selected_column()
+                .and_then(|c| window.iter().position(|&i| i == c))
+                .map(|i| if COLUMN_SEPARATORS { i * 2 } else { i }),
+        );
 
-                Row::new(vec![
-                    Cell::from(c.coin.clone()),
-                    Cell::from(format!(
-                        "{:.6}%",
-                        if c.current_exchange & 1 == 1 {
-                            funding_display * 100.0
-                        } else {
-                            funding_display
-                        }
-                    ))
-                    .style(Style::new().fg(funding_color)),
-                    Cell::from(open_interest_display),
-                    Cell::from(exchange_display).style(Style::new().fg(exchange_color)),
-                ])
-                .style(Style::new().fg(self.colors.row_fg).bg(bg))
-            });
+        let table = Table::new(rows, constraints)
+            .header(header)
+            .row_highlight_style(selected_row_style)
+            .column_highlight_style(selected_col_style)
+            .cell_highlight_style(selected_cell_style)
+            .highlight_spacing(HighlightSpacing::Always)
+            .bg(self.colors.buffer_bg);
 
-        let table = Table::new(
-            rows,
-            [
-                Constraint::Fill(1),
-                Constraint::Fill(1),
-                Constraint::Fill(1),
-                Constraint::Length(8),
-            ],
-        )
-        .header(header)
-        .row_highlight_style(selected_row_style)
-        .column_highlight_style(selected_col_style)
-        .cell_highlight_style(selected_cell_style)
-        .highlight_spacing(HighlightSpacing::Always)
-        .bg(self.colors.buffer_bg);
+        frame.render_stateful_widget(table, area, &mut render_state);
+        *self.state.offset_mut() = render_state.offset();
 
-        frame.render_stateful_widget(table, area, &mut self.state);
+        // Data rows on screen below the header, leaving out repeated headers
+        // and group separators, so paging moves by what is actually visible
+        let table_rows = area.height.saturating_sub(1) as usize;
+        self.page_rows =
+            navigation::data_rows_in_view(&display_rows, render_state.offset(), table_rows);
     }
 
     fn render_scrollbar(&mut self, frame: &mut Frame, area: Rect) {
+        // Rows visible below the header, in the same ITEM_HEIGHT units as the
+        // content length, so the thumb reflects the visible share of the
+        // table. Recomputed every frame, which also covers resizes.
+        let visible_rows = area.height.saturating_sub(1) as usize;
+        self.scroll_state = self
+            .scroll_state
+            .viewport_content_length(visible_rows * ITEM_HEIGHT);
         frame.render_stateful_widget(
             Scrollbar::default()
                 .orientation(ScrollbarOrientation::VerticalRight)
@@ -564,19 +2953,122 @@ impl TuiApp {
         );
     }
 
+    /// Footer text for the market funding index, in the current round.
+    fn market_index_summary(&self) -> String {
+        let Some(index) = MarketIndex::from_coins(&self.items, self.smoothed) else {
+            return " | index: N/A".to_string();
+        };
+        let display = |hourly: f64| annualize(hourly, self.round, self.compound) * 100.0;
+        let precision = precision_for(self.round);
+        let weighted = match index.weighted {
+            Some(weighted) => format!("{:.*}%", precision, display(weighted)),
+            None => "N/A".to_string(),
+        };
+        format!(
+            " | OI-weighted index: {} (median {:.*}%, {} coins)",
+            weighted,
+            precision,
+            display(index.median),
+            index.coins
+        )
+    }
+
+    /// Footer text totalling the estimated funding paid (or received, when
+    /// negative) by sized positions over the current round.
+    fn funding_bill(&self) -> Option<String> {
+        let costs: Vec<f64> = POSITIONS
+            .iter()
+            .filter_map(|position| {
+                let coin = self.items.iter().find(|c| c.coin == position.coin)?;
+                if !coin.has_data() || coin.is_funding_suspect() {
+                    return None;
+                }
+                position.hourly_cost_usd(coin, coin.hourly_funding_value(self.smoothed))
+            })
+            .collect();
+        if costs.is_empty() {
+            return None;
+        }
+        let total = annualize(costs.iter().sum(), self.round, false);
+        let direction = if total > 0.0 { "paying" } else { "receiving" };
+        let amount = if self.compact_numbers {
+            format_usd(total.abs()).trim_end().to_string()
+        } else {
+            format!("${:.2}", total.abs())
+        };
+        Some(format!(
+            " | funding bill ({}): {} {}",
+            self.round.label(),
+            direction,
+            amount
+        ))
+    }
+
     fn render_footer(&self, frame: &mut Frame, area: Rect) {
-        let info_footer = Paragraph::new(format!("{:?}{:?}", INFO_TEXT, self.exchange))
-            .style(
-                Style::new()
-                    .fg(self.colors.row_fg)
-                    .bg(self.colors.buffer_bg),
+        let alerting = if self.is_filter_inverted() && self.alerting_only {
+            format!(
+                "inverted filter, all but alerting: {} hidden",
+                self.alerting_count()
             )
-            .centered()
-            .block(
-                Block::bordered()
-                    .border_type(BorderType::Double)
-                    .border_style(Style::new().fg(self.colors.footer_border_color)),
-            );
+        } else if self.is_filter_inverted() {
+            format!("inverted filter | alerting: {}", self.alerting_count())
+        } else if self.alerting_only {
+            format!("alerting only: {}", self.alerting_count())
+        } else {
+            format!("alerting: {}", self.alerting_count())
+        };
+        let mut status = format!(
+            "{} {} | {} | colors: {}",
+            Timezone::display().format_now("%H:%M:%S"),
+            Timezone::display().label(),
+            alerting,
+            self.color_mode.label()
+        );
+        for exchange in exchanges_for(self.get_exchange()) {
+            let venue = self.health.venue(exchange.venue());
+            if !venue.connected && venue.last_update.is_some() {
+                status.push_str(&format!(" | {}: reconnecting", exchange.name()));
+            }
+        }
+        for venue in VENUES {
+            let failed = self.health.venue(venue).failed_subscriptions;
+            if failed > 0 {
+                status.push_str(&format!(
+                    " | {}: {} coins failed to subscribe",
+                    venue_name(venue),
+                    failed
+                ));
+            }
+        }
+        if let Some(bill) = self.funding_bill() {
+            status.push_str(&bill);
+        }
+        if self.show_market_index {
+            status.push_str(&self.market_index_summary());
+        }
+        let pegged = self.hidden_pegged_count();
+        if pegged > 0 && !self.is_filter_inverted() {
+            status.push_str(&format!(" | pegged hidden: {}", pegged));
+        }
+        if self.dropped_updates > 0 {
+            status.push_str(&format!(" | dropped: {}", self.dropped_updates));
+        }
+        if let Some(summary) = &self.quiet_summary {
+            status.push_str(&format!(" | {}", summary));
+        }
+        let info_footer =
+            Paragraph::new(format!("{:?}{:?} | {}", INFO_TEXT, self.exchange, status))
+                .style(
+                    Style::new()
+                        .fg(self.colors.row_fg)
+                        .bg(self.colors.buffer_bg),
+                )
+                .centered()
+                .block(
+                    Block::bordered()
+                        .border_type(BorderType::Double)
+                        .border_style(Style::new().fg(self.colors.footer_border_color)),
+                );
         frame.render_widget(info_footer, area);
     }
 }
@@ -1,4 +1,52 @@
-use ratatui::style::{Color, palette::tailwind};
+use ratatui::style::{Color, Modifier, Style, palette::tailwind};
+
+use crate::config::MAGNITUDE_HUE;
+use crate::data::Trend;
+
+/// What funding colors convey.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ColorMode {
+    /// Red below the baseline, green once it is cleared by the threshold.
+    Signed,
+    /// One hue, more intense the further a rate is from the baseline on
+    /// either side.
+    Magnitude,
+}
+
+impl ColorMode {
+    pub fn toggled(self) -> Self {
+        match self {
+            ColorMode::Signed => ColorMode::Magnitude,
+            ColorMode::Magnitude => ColorMode::Signed,
+        }
+    }
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            ColorMode::Signed => "signed",
+            ColorMode::Magnitude => "magnitude",
+        }
+    }
+}
+
+/// How a highlighted row, column or cell is drawn.
+#[derive(Clone, Copy, Debug)]
+pub enum Highlight {
+    /// Reverse video over the palette accent color.
+    Reverse,
+    /// Palette accent as the foreground, background left alone.
+    Accent,
+    /// Fixed colors, for palettes where reverse video is hard to read.
+    Explicit { fg: Color, bg: Color },
+}
+
+/// Highlight styles for the table cursor.
+#[derive(Clone, Copy, Debug)]
+pub struct SelectionStyle {
+    pub row: Highlight,
+    pub column: Highlight,
+    pub cell: Highlight,
+}
 
 pub struct TableColors {
     pub buffer_bg: Color,
@@ -11,6 +59,7 @@ pub struct TableColors {
     pub normal_row_color: Color,
     pub alt_row_color: Color,
     pub footer_border_color: Color,
+    pub flash_bg: Color,
 }
 
 impl TableColors {
@@ -26,18 +75,140 @@ impl TableColors {
             normal_row_color: tailwind::SLATE.c950,
             alt_row_color: tailwind::SLATE.c900,
             footer_border_color: color.c400,
+            flash_bg: color.c700,
         }
     }
 
-    pub fn funding_rate_color(&self, funding: f64) -> Color {
-        use crate::config::FUNDING_RATE_THRESHOLD;
+    /// Resolves a highlight against a palette accent color. Highlights clear
+    /// DIM so dimmed columns stay readable when selected.
+    pub fn highlight_style(&self, highlight: Highlight, accent: Color) -> Style {
+        let style = Style::default().remove_modifier(Modifier::DIM);
+        match highlight {
+            Highlight::Reverse => style.add_modifier(Modifier::REVERSED).fg(accent),
+            Highlight::Accent => style.fg(accent),
+            Highlight::Explicit { fg, bg } => style.fg(fg).bg(bg),
+        }
+    }
 
-        if funding < 0.0 {
+    /// Colors funding against `baseline` (zero, or a cost of capital): red
+    /// below it, green once it clears the baseline by the threshold.
+    pub fn funding_rate_color(&self, funding: f64, threshold: f64, baseline: f64) -> Color {
+        if funding < baseline {
             Color::Red
-        } else if funding > FUNDING_RATE_THRESHOLD {
+        } else if funding - baseline > threshold {
             Color::Green
         } else {
             self.row_fg
         }
     }
+
+    /// Shade of `MAGNITUDE_HUE` by how many thresholds `funding` is away
+    /// from `baseline`, regardless of sign.
+    pub fn funding_magnitude_color(&self, funding: f64, threshold: f64, baseline: f64) -> Color {
+        let steps = (funding - baseline).abs() / threshold.max(f64::MIN_POSITIVE);
+        if steps < 0.5 {
+            self.row_fg
+        } else if steps < 1.0 {
+            MAGNITUDE_HUE.c200
+        } else if steps < 2.0 {
+            MAGNITUDE_HUE.c400
+        } else if steps < 4.0 {
+            MAGNITUDE_HUE.c500
+        } else {
+            MAGNITUDE_HUE.c600
+        }
+    }
+
+    /// Colors the time since a funding stamp against the venue's funding
+    /// interval, so a round that is merely in progress stays plain.
+    pub fn venue_age_color(&self, age_secs: f64, interval_secs: f64) -> Color {
+        use crate::config::VENUE_AGE_WARN_SECS;
+
+        let overdue = age_secs - interval_secs - VENUE_AGE_WARN_SECS;
+        if overdue > interval_secs {
+            Color::Red
+        } else if overdue > 0.0 {
+            Color::Yellow
+        } else {
+            self.row_fg
+        }
+    }
+
+    /// Colors a predicted-minus-realized funding delta by sign, bold once it
+    /// exceeds the funding threshold.
+    pub fn funding_delta_style(&self, delta: f64, threshold: f64) -> Style {
+        let color = if delta > 0.0 {
+            Color::Green
+        } else if delta < 0.0 {
+            Color::Red
+        } else {
+            self.row_fg
+        };
+        let style = Style::new().fg(color);
+        if delta.abs() > threshold {
+            style.add_modifier(Modifier::BOLD)
+        } else {
+            style
+        }
+    }
+
+    /// Green for positive values, red for negative.
+    pub fn sign_color(&self, value: f64) -> Color {
+        if value > 0.0 {
+            Color::Green
+        } else if value < 0.0 {
+            Color::Red
+        } else {
+            self.row_fg
+        }
+    }
+
+    pub fn trend_color(&self, trend: Trend) -> Color {
+        match trend {
+            Trend::Rising => Color::Green,
+            Trend::Falling => Color::Red,
+            Trend::Flat => self.row_fg,
+        }
+    }
+
+    /// Marks a held coin: red while the position pays funding, green while
+    /// it receives.
+    pub fn position_style(&self, rate_paid: f64) -> Style {
+        let color = if rate_paid > 0.0 {
+            Color::Red
+        } else if rate_paid < 0.0 {
+            Color::Green
+        } else {
+            self.row_fg
+        };
+        Style::new()
+            .fg(color)
+            .add_modifier(Modifier::BOLD | Modifier::UNDERLINED)
+    }
+
+    /// Highlights coins in the top or bottom decile of funding.
+    pub fn funding_rank_color(&self, percentile: f64) -> Color {
+        if percentile >= 90.0 {
+            Color::Green
+        } else if percentile <= 10.0 {
+            Color::Red
+        } else {
+            self.row_fg
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn round_age_warns_only_once_the_round_is_overdue() {
+        let colors = TableColors::new(&tailwind::BLUE);
+        let hour = 3600.0;
+        assert_eq!(colors.venue_age_color(3500.0, hour), colors.row_fg);
+        assert_eq!(colors.venue_age_color(hour + 60.0, hour), colors.row_fg);
+        assert_eq!(colors.venue_age_color(2.0 * hour, hour), Color::Yellow);
+        assert_eq!(colors.venue_age_color(3.0 * hour, hour), Color::Red);
+    }
 }
@@ -0,0 +1,231 @@
+use ratatui::layout::Constraint;
+use serde::{Deserialize, Serialize};
+
+use crate::config::{COLUMN_WIDTH_STRATEGY, COLUMN_WIDTHS, COUNTDOWN_BAR_WIDTH};
+use crate::data::{Divergence, DivergenceRule, FundingRateRound, annualize, precision_for};
+
+/// How the table divides its width between columns.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ColumnWidths {
+    /// Every variable-width column gets an equal share.
+    Equal,
+    /// Variable-width columns share the width in proportion to their
+    /// typical content, so short names don't get as much room as rates.
+    Content,
+    /// Widths from `COLUMN_WIDTHS`; columns not listed size by content.
+    Explicit,
+}
+
+/// A table column. The visible set and order is decided by `TuiApp`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum Column {
+    Coin,
+    Funding,
+    /// Funding over a fixed period, shown in multi-period mode.
+    FundingPeriod(FundingRateRound),
+    NetFunding,
+    OpenInterest,
+    Exchange,
+    VenueAge,
+    FundingRank,
+    Basis,
+    Premium,
+    FundingDelta,
+    DailyChange,
+    DailyYield,
+    BlendedFunding,
+    VenueCount,
+    Countdown,
+    CarryScore,
+    LastPrice,
+    Divergence,
+}
+
+impl Column {
+    /// The funding columns, in display order: one per period in `periods`
+    /// in multi-period mode, otherwise the single funding column.
+    pub fn funding_columns(multi_period: bool, periods: &[FundingRateRound]) -> Vec<Column> {
+        if multi_period && !periods.is_empty() {
+            periods
+                .iter()
+                .map(|&round| Column::FundingPeriod(round))
+                .collect()
+        } else {
+            vec![Column::Funding]
+        }
+    }
+
+    /// The column that takes over this column's sort when multi-period mode
+    /// changes, so a funding sort survives the switch.
+    pub fn for_period_mode(self, multi_period: bool, periods: &[FundingRateRound]) -> Column {
+        match self {
+            Column::Funding | Column::FundingPeriod(_) => {
+                Column::funding_columns(multi_period, periods)[0]
+            }
+            column => column,
+        }
+    }
+
+    /// Width constraint under the configured `COLUMN_WIDTH_STRATEGY`.
+    pub fn constraint(&self) -> Constraint {
+        if COLUMN_WIDTH_STRATEGY == ColumnWidths::Explicit
+            && let Some((_, width)) = COLUMN_WIDTHS.iter().find(|(c, _)| c == self)
+        {
+            return Constraint::Length(*width);
+        }
+        match self.base_constraint() {
+            Constraint::Fill(_) if COLUMN_WIDTH_STRATEGY != ColumnWidths::Equal => {
+                Constraint::Fill(self.content_width())
+            }
+            constraint => constraint,
+        }
+    }
+
+    /// Columns with a fixed-size value get a fixed width; the rest fill.
+    fn base_constraint(&self) -> Constraint {
+        match self {
+            Column::Coin
+            | Column::Funding
+            | Column::FundingPeriod(_)
+            | Column::NetFunding
+            | Column::OpenInterest
+            | Column::Basis
+            | Column::FundingDelta
+            | Column::BlendedFunding
+            | Column::Divergence
+            | Column::LastPrice => Constraint::Fill(1),
+            Column::DailyChange | Column::Premium => Constraint::Length(9),
+            Column::DailyYield => Constraint::Length(14),
+            Column::Exchange => Constraint::Length(8),
+            Column::VenueAge => Constraint::Length(9),
+            Column::FundingRank => Constraint::Length(6),
+            Column::VenueCount | Column::CarryScore => Constraint::Length(7),
+            Column::Countdown => Constraint::Length(COUNTDOWN_BAR_WIDTH as u16 + 9),
+        }
+    }
+
+    /// Narrowest width the column is readable at, used to decide how many
+    /// columns fit before the table scrolls horizontally.
+    pub fn min_width(&self) -> u16 {
+        match self.constraint() {
+            Constraint::Length(width) => width,
+            _ => self.content_width(),
+        }
+    }
+
+    /// Typical width of the column's values, e.g. `-0.001250% ` for rates.
+    fn content_width(&self) -> u16 {
+        match self {
+            Column::Coin => 10,
+            Column::LastPrice => 12,
+            Column::Funding
+            | Column::FundingPeriod(_)
+            | Column::NetFunding
+            | Column::DailyYield => 14,
+            Column::OpenInterest => 14,
+            Column::Basis | Column::FundingDelta | Column::BlendedFunding | Column::Divergence => {
+                13
+            }
+            Column::DailyChange | Column::Premium => 9,
+            Column::Exchange => 8,
+            Column::VenueAge => 9,
+            Column::FundingRank => 6,
+            Column::VenueCount | Column::CarryScore => 7,
+            Column::Countdown => COUNTDOWN_BAR_WIDTH as u16 + 9,
+        }
+    }
+
+    pub fn is_numeric(&self) -> bool {
+        !matches!(self, Column::Coin | Column::Exchange | Column::Countdown)
+    }
+
+    pub fn is_sortable(&self) -> bool {
+        !matches!(self, Column::Exchange)
+    }
+}
+
+/// A divergence cell's value: the z-score under a z-score rule, otherwise
+/// the gap in percent over `round`.
+pub fn divergence_text(
+    divergence: &Divergence,
+    rule: DivergenceRule,
+    round: FundingRateRound,
+) -> String {
+    match rule {
+        DivergenceRule::ZScore(_) => match divergence.z_score {
+            Some(z) => format!("{:+.1}σ", z),
+            None => "0.0σ".to_string(),
+        },
+        DivergenceRule::Gap(_) => format!(
+            "{:+.*}%",
+            precision_for(round),
+            annualize(divergence.gap, round, false) * 100.0
+        ),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const PERIODS: &[FundingRateRound] = &[FundingRateRound::Hourly, FundingRateRound::Annually];
+
+    #[test]
+    fn multi_period_mode_shows_one_column_per_period() {
+        assert_eq!(
+            Column::funding_columns(true, PERIODS),
+            [
+                Column::FundingPeriod(FundingRateRound::Hourly),
+                Column::FundingPeriod(FundingRateRound::Annually)
+            ]
+        );
+        assert_eq!(Column::funding_columns(false, PERIODS), [Column::Funding]);
+        assert_eq!(Column::funding_columns(true, &[]), [Column::Funding]);
+    }
+
+    #[test]
+    fn funding_sort_carries_across_period_modes() {
+        let first = Column::FundingPeriod(FundingRateRound::Hourly);
+        assert_eq!(Column::Funding.for_period_mode(true, PERIODS), first);
+        assert_eq!(
+            Column::FundingPeriod(FundingRateRound::Annually).for_period_mode(false, PERIODS),
+            Column::Funding
+        );
+        assert_eq!(
+            Column::OpenInterest.for_period_mode(true, PERIODS),
+            Column::OpenInterest
+        );
+    }
+
+    #[test]
+    fn divergence_text_follows_the_rule() {
+        let divergence = Divergence {
+            average: 0.0001,
+            gap: 0.00005,
+            z_score: Some(2.345),
+            samples: 10,
+        };
+        assert_eq!(
+            divergence_text(
+                &divergence,
+                DivergenceRule::ZScore(2.0),
+                FundingRateRound::Hourly
+            ),
+            "+2.3σ"
+        );
+        let flat = Divergence {
+            z_score: None,
+            ..divergence
+        };
+        assert_eq!(
+            divergence_text(&flat, DivergenceRule::ZScore(2.0), FundingRateRound::Hourly),
+            "0.0σ"
+        );
+        let gap = divergence_text(
+            &divergence,
+            DivergenceRule::Gap(0.0),
+            FundingRateRound::Hourly,
+        );
+        assert!(gap.starts_with("+0.005"), "{}", gap);
+    }
+}
@@ -0,0 +1,30 @@
+use std::io;
+
+use crate::config::FAVORITES_STATE_FILE;
+use crate::paths::{load_state, save_state};
+
+/// How favorited coins are ordered at the top of the table.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum FavoritesSort {
+    /// In the order they were favorited, rearranged by hand.
+    Manual,
+    /// By the active sort column, like the rest of the table.
+    Global,
+}
+
+/// Favorited coins in their saved order, one per line in the state file.
+pub fn load_favorites() -> Vec<String> {
+    load_state(FAVORITES_STATE_FILE)
+        .map(|s| {
+            s.lines()
+                .map(str::trim)
+                .filter(|l| !l.is_empty())
+                .map(str::to_string)
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
+pub fn save_favorites(favorites: &[String]) -> io::Result<()> {
+    save_state(FAVORITES_STATE_FILE, &favorites.join("\n"))
+}
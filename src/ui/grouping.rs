@@ -0,0 +1,149 @@
+use std::collections::HashMap;
+
+use crate::data::{CoinData, venue_short_name};
+
+/// Contents of a group's separator row.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct GroupSummary {
+    pub label: String,
+    pub count: usize,
+    funding_sum: f64,
+    priced: usize,
+}
+
+impl GroupSummary {
+    /// Average hourly funding of the group's rows, leaving out suspect
+    /// values. `None` when every row is suspect.
+    pub fn average_funding(&self) -> Option<f64> {
+        (self.priced > 0).then(|| self.funding_sum / self.priced as f64)
+    }
+}
+
+/// Field the table can be grouped by, with a separator row above each group.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum GroupBy {
+    /// Positive, flat and negative funding.
+    FundingSign,
+    /// The venue the row's values come from.
+    Venue,
+    /// First letter of the coin name.
+    FirstLetter,
+    /// Favorites, then everything else.
+    Favorite,
+}
+
+impl GroupBy {
+    /// The group a coin belongs to, as an order key and a label. Groups are
+    /// shown in ascending key order.
+    pub fn group_of(&self, coin: &CoinData, favorites: &[String]) -> (usize, String) {
+        match self {
+            GroupBy::FundingSign if coin.funding > 0.0 => (0, "+ funding".to_string()),
+            GroupBy::FundingSign if coin.funding < 0.0 => (2, "- funding".to_string()),
+            GroupBy::FundingSign => (1, "flat".to_string()),
+            GroupBy::Venue => match coin.source_venue {
+                Some(venue) => (venue as usize, venue_short_name(venue).to_string()),
+                None => (usize::MAX, "no data".to_string()),
+            },
+            GroupBy::FirstLetter => {
+                let letter = coin.coin.chars().next().unwrap_or(' ').to_ascii_uppercase();
+                (letter as usize, letter.to_string())
+            }
+            GroupBy::Favorite if favorites.contains(&coin.coin) => (0, "★ favorites".to_string()),
+            GroupBy::Favorite => (1, "others".to_string()),
+        }
+    }
+
+    /// Orders `items` by group. The sort is stable, so rows keep their
+    /// existing order within each group.
+    pub fn sort_into_groups(&self, items: &mut [CoinData], favorites: &[String]) {
+        items.sort_by_key(|c| self.group_of(c, favorites).0);
+    }
+
+    /// Separator row contents for each group key among `coins`.
+    pub fn summarize<'a>(
+        &self,
+        coins: impl IntoIterator<Item = &'a CoinData>,
+        favorites: &[String],
+        smoothed: bool,
+    ) -> HashMap<usize, GroupSummary> {
+        let mut groups: HashMap<usize, GroupSummary> = HashMap::new();
+        for c in coins {
+            let (key, label) = self.group_of(c, favorites);
+            let group = groups.entry(key).or_insert_with(|| GroupSummary {
+                label,
+                ..GroupSummary::default()
+            });
+            group.count += 1;
+            if !c.is_funding_suspect() {
+                group.funding_sum += c.hourly_funding_value(smoothed);
+                group.priced += 1;
+            }
+        }
+        groups
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::data::{CoinUpdate, HYPERLIQUID};
+
+    fn coin(name: &str, funding: f64) -> CoinData {
+        let mut coin = CoinData::new(name.to_string());
+        coin.apply(&CoinUpdate::new(
+            name.to_string(),
+            funding,
+            10.0,
+            100.0,
+            1,
+            HYPERLIQUID,
+        ));
+        coin
+    }
+
+    fn names(items: &[CoinData]) -> Vec<&str> {
+        items.iter().map(|c| c.coin.as_str()).collect()
+    }
+
+    #[test]
+    fn grouping_keeps_the_sort_order_within_each_group() {
+        let mut items = vec![
+            coin("ETH", -0.0002),
+            coin("BTC", 0.0003),
+            coin("SOL", -0.0001),
+            coin("DOGE", 0.0001),
+        ];
+        GroupBy::FundingSign.sort_into_groups(&mut items, &[]);
+        assert_eq!(names(&items), ["BTC", "DOGE", "ETH", "SOL"]);
+    }
+
+    #[test]
+    fn favorites_group_comes_first() {
+        let mut items = vec![coin("BTC", 0.0001), coin("ETH", 0.0001)];
+        GroupBy::Favorite.sort_into_groups(&mut items, &["ETH".to_string()]);
+        assert_eq!(names(&items), ["ETH", "BTC"]);
+    }
+
+    #[test]
+    fn summary_counts_every_row_but_averages_only_sane_funding() {
+        let sane = [coin("BTC", 0.0001), coin("ETH", 0.0003)];
+        let suspect = coin("BAD", 1.0);
+        assert!(suspect.is_funding_suspect());
+
+        let groups = GroupBy::FundingSign.summarize(sane.iter().chain([&suspect]), &[], false);
+        let positive = &groups[&0];
+        assert_eq!(positive.label, "+ funding");
+        assert_eq!(positive.count, 3);
+        let expected =
+            (sane[0].hourly_funding_value(false) + sane[1].hourly_funding_value(false)) / 2.0;
+        assert_eq!(positive.average_funding(), Some(expected));
+    }
+
+    #[test]
+    fn all_suspect_group_has_no_average() {
+        let suspect = coin("BAD", 1.0);
+        let groups = GroupBy::FundingSign.summarize([&suspect], &[], false);
+        assert_eq!(groups[&0].count, 1);
+        assert_eq!(groups[&0].average_funding(), None);
+    }
+}
@@ -0,0 +1,122 @@
+use crate::config::{DIAGNOSTICS_KEY, OPEN_TRADE_PAGE_KEY};
+
+/// Character keys the table binds itself. The configurable keys only act
+/// when they are not one of these.
+pub const BUILT_IN_KEYS: &[char] = &[
+    'q', 'j', 'k', 'h', 'l', 'r', 'F', 'R', 'y', 'm', 't', 'T', 'C', 's', 'd', 'H', 'G', 'Z', 'g',
+    'Y', 'z', 'W', 'X', 'p', 'a', 'n', 'b', 'P', 'i', 'w', '%', 'M', '*', 'K', 'J', 'D', 'V', 'N',
+    'A', 'B', 'v', 'c', 'f', 'o', 'O', 'L', 'S', 'e', 'E', 'U', '/', '=', '?',
+];
+
+/// Table key bindings as `(keys, action)`, in the order the help overlay
+/// (`?`) lists them.
+pub const KEY_HELP: &[(&str, &str)] = &[
+    ("q / Esc", "Quit"),
+    ("j / k, ↓ / ↑", "Move row"),
+    ("PgDn / PgUp", "Page"),
+    ("h / l, ← / →", "Move column"),
+    ("Shift + ← / →", "Cycle palette"),
+    ("Enter", "Sort by column"),
+    ("Tab / Shift + Tab", "Switch tab"),
+    ("/", "Search"),
+    ("=", "Alert threshold"),
+    ("s", "Switch exchange"),
+    ("R", "Refresh coin list"),
+    ("r", "Funding round"),
+    ("F", "Funding per period"),
+    ("y", "Compound APR"),
+    ("m", "Smoothed funding"),
+    ("t", "Open interest in USD"),
+    ("T", "Open interest in both units"),
+    ("C", "Compact numbers"),
+    ("G", "Color mode"),
+    ("d", "Coin detail"),
+    ("H", "Funding histogram"),
+    ("Z", "Hide pegged coins"),
+    ("g", "Funding countdown"),
+    ("Y", "Carry score"),
+    ("z", "Divergence"),
+    ("W", "Last price"),
+    ("X", "Group rows"),
+    ("p", "Repeat header"),
+    ("a", "Round age"),
+    ("n", "Funding rank"),
+    ("b", "Basis"),
+    ("P", "Premium"),
+    ("i", "Market index"),
+    ("w", "Trend"),
+    ("%", "24h change"),
+    ("D", "Daily yield"),
+    ("V", "Venue count"),
+    ("N", "Net of baseline APR"),
+    ("B", "Blended funding"),
+    ("v", "Funding delta"),
+    ("M", "Mute coin alerts"),
+    ("*", "Favorite coin"),
+    ("K / J", "Move favorite up / down"),
+    ("o", "Alerting coins only"),
+    ("O", "Invert filter"),
+    ("f", "Follow selection"),
+    ("A", "Auto scroll"),
+    ("c", "Copy symbol"),
+    ("L", "Layouts"),
+    ("S", "Save layout"),
+    ("e / E", "Export alerts as CSV / JSON"),
+    ("U", "Export universe"),
+    ("?", "Key help"),
+];
+
+/// Keys set in config, with the setting that names them and their action.
+pub const CONFIGURED_KEYS: [(&str, char, &str); 2] = [
+    ("DIAGNOSTICS_KEY", DIAGNOSTICS_KEY, "Write diagnostics"),
+    (
+        "OPEN_TRADE_PAGE_KEY",
+        OPEN_TRADE_PAGE_KEY,
+        "Open trade page",
+    ),
+];
+
+/// Configured keys that a built-in binding takes precedence over, so they
+/// never act.
+pub fn shadowed_keys() -> Vec<(&'static str, char)> {
+    CONFIGURED_KEYS
+        .iter()
+        .filter(|(_, key, _)| BUILT_IN_KEYS.contains(key))
+        .map(|(setting, key, _)| (*setting, *key))
+        .collect()
+}
+
+/// Every binding the help overlay lists: the built-in ones, then the
+/// configured keys that aren't shadowed.
+pub fn key_help() -> Vec<(String, &'static str)> {
+    let built_in = KEY_HELP
+        .iter()
+        .map(|(keys, action)| (keys.to_string(), *action));
+    let configured = CONFIGURED_KEYS
+        .iter()
+        .filter(|(_, key, _)| !BUILT_IN_KEYS.contains(key))
+        .map(|(_, key, action)| (key.to_string(), *action));
+    built_in.chain(configured).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn every_built_in_key_is_listed() {
+        for key in BUILT_IN_KEYS {
+            let key = key.to_string();
+            let listed = KEY_HELP.iter().any(|(keys, _)| {
+                *keys == key || keys.split([' ', '/', ',']).any(|token| token == key)
+            });
+            assert!(listed, "{:?} is missing from KEY_HELP", key);
+        }
+    }
+
+    #[test]
+    fn configured_keys_are_not_shadowed() {
+        assert_eq!(shadowed_keys(), Vec::new());
+        assert_ne!(DIAGNOSTICS_KEY, OPEN_TRADE_PAGE_KEY);
+    }
+}
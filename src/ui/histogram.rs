@@ -0,0 +1,29 @@
+use crate::config::{HISTOGRAM_BIN_APR, HISTOGRAM_RANGE_APR};
+
+/// Number of bins `HISTOGRAM_RANGE_APR` splits into at `HISTOGRAM_BIN_APR`.
+pub fn bin_count() -> usize {
+    let (low, high) = HISTOGRAM_RANGE_APR;
+    ((high - low) / HISTOGRAM_BIN_APR).ceil().max(1.0) as usize
+}
+
+/// Bin an annualized rate falls in. Rates outside the range go to the end
+/// bins rather than widening the axis.
+pub fn bin_of(apr: f64) -> usize {
+    let (low, _) = HISTOGRAM_RANGE_APR;
+    let bin = ((apr - low) / HISTOGRAM_BIN_APR).floor().max(0.0) as usize;
+    bin.min(bin_count() - 1)
+}
+
+/// Lower edge of a bin as an annualized fraction.
+pub fn bin_start(bin: usize) -> f64 {
+    HISTOGRAM_RANGE_APR.0 + bin as f64 * HISTOGRAM_BIN_APR
+}
+
+/// Count of rates per bin, lowest bin first.
+pub fn funding_bins(aprs: impl IntoIterator<Item = f64>) -> Vec<u64> {
+    let mut counts = vec![0; bin_count()];
+    for apr in aprs.into_iter().filter(|apr| apr.is_finite()) {
+        counts[bin_of(apr)] += 1;
+    }
+    counts
+}
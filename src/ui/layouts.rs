@@ -0,0 +1,150 @@
+use color_eyre::Result;
+use color_eyre::eyre::WrapErr;
+use serde::{Deserialize, Serialize};
+
+use crate::config::LAYOUTS_STATE_FILE;
+use crate::data::FundingRateRound;
+use crate::paths::{load_state, save_state, write_debug_log};
+use crate::ui::Column;
+
+/// A named view to switch to in one step: the columns shown, the sort, the
+/// row filter, the funding period and the color theme.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct SavedLayout {
+    pub name: String,
+    pub columns: Vec<Column>,
+    pub sorted_column: Option<Column>,
+    pub alerting_only: bool,
+    pub filter_inverted: bool,
+    pub round: FundingRateRound,
+    pub color_index: usize,
+    /// Shipped with the app rather than saved by the user; can't be deleted.
+    #[serde(skip)]
+    pub builtin: bool,
+}
+
+/// Layouts shipped with the app.
+fn builtin_layouts() -> Vec<SavedLayout> {
+    vec![
+        SavedLayout {
+            name: "arb scan".to_string(),
+            columns: vec![
+                Column::Coin,
+                Column::Funding,
+                Column::OpenInterest,
+                Column::Exchange,
+                Column::Basis,
+                Column::FundingDelta,
+                Column::BlendedFunding,
+            ],
+            sorted_column: Some(Column::FundingDelta),
+            alerting_only: false,
+            filter_inverted: false,
+            round: FundingRateRound::Annually,
+            color_index: 0,
+            builtin: true,
+        },
+        SavedLayout {
+            name: "my book".to_string(),
+            columns: vec![
+                Column::Coin,
+                Column::Funding,
+                Column::OpenInterest,
+                Column::Exchange,
+                Column::DailyChange,
+                Column::DailyYield,
+            ],
+            sorted_column: Some(Column::DailyYield),
+            alerting_only: false,
+            filter_inverted: false,
+            round: FundingRateRound::Daily,
+            color_index: 0,
+            builtin: true,
+        },
+    ]
+}
+
+/// Parses the saved layouts file; a missing file holds none. A file that
+/// doesn't parse, e.g. after a column was renamed, is an error so callers
+/// never write over layouts they couldn't read.
+fn parse_saved_layouts(contents: Option<&str>) -> Result<Vec<SavedLayout>> {
+    match contents {
+        Some(contents) => serde_json::from_str(contents).wrap_err_with(|| {
+            format!(
+                "saved layouts in {} can't be read and were left untouched",
+                LAYOUTS_STATE_FILE
+            )
+        }),
+        None => Ok(Vec::new()),
+    }
+}
+
+/// Layouts saved by the user, in the order they were first saved.
+fn load_saved_layouts() -> Result<Vec<SavedLayout>> {
+    parse_saved_layouts(load_state(LAYOUTS_STATE_FILE).as_deref())
+}
+
+/// Built-in layouts followed by saved ones. A saved layout replaces a
+/// built-in one with the same name. Unreadable saved layouts are logged and
+/// left out.
+pub fn load_layouts() -> Vec<SavedLayout> {
+    let saved = load_saved_layouts().unwrap_or_else(|e| {
+        write_debug_log("LAYOUTS", &format!("{:#}", e));
+        Vec::new()
+    });
+    let mut layouts: Vec<SavedLayout> = builtin_layouts()
+        .into_iter()
+        .filter(|builtin| !saved.iter().any(|s| s.name == builtin.name))
+        .collect();
+    layouts.extend(saved);
+    layouts
+}
+
+/// Saves a layout under its name, replacing any saved layout with the same
+/// name.
+pub fn save_layout(layout: SavedLayout) -> Result<()> {
+    let mut saved = load_saved_layouts()?;
+    match saved.iter_mut().find(|s| s.name == layout.name) {
+        Some(existing) => *existing = layout,
+        None => saved.push(layout),
+    }
+    save_state(LAYOUTS_STATE_FILE, &serde_json::to_string_pretty(&saved)?)?;
+    Ok(())
+}
+
+/// Removes a saved layout by name.
+pub fn delete_layout(name: &str) -> Result<()> {
+    let mut saved = load_saved_layouts()?;
+    saved.retain(|s| s.name != name);
+    save_state(LAYOUTS_STATE_FILE, &serde_json::to_string_pretty(&saved)?)?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn missing_file_holds_no_layouts() {
+        assert!(parse_saved_layouts(None).unwrap().is_empty());
+    }
+
+    #[test]
+    fn saved_layouts_round_trip() {
+        let layouts = builtin_layouts();
+        let json = serde_json::to_string_pretty(&layouts).unwrap();
+        let parsed = parse_saved_layouts(Some(&json)).unwrap();
+        assert_eq!(parsed.len(), layouts.len());
+        assert_eq!(parsed[0].name, "arb scan");
+        assert_eq!(parsed[0].columns, layouts[0].columns);
+    }
+
+    #[test]
+    fn unreadable_file_is_an_error() {
+        assert!(parse_saved_layouts(Some("{ not json")).is_err());
+        let json = serde_json::to_string(&builtin_layouts())
+            .unwrap()
+            .replace("\"OpenInterest\"", "\"RenamedColumn\"");
+        assert!(parse_saved_layouts(Some(&json)).is_err());
+    }
+}
@@ -1,5 +1,25 @@
 pub mod app;
 pub mod colors;
+pub mod columns;
+pub mod favorites;
+pub mod grouping;
+pub mod help;
+pub mod histogram;
+pub mod layouts;
+pub mod navigation;
+pub mod prompt;
+pub mod search;
+pub mod selection;
+pub mod sorting;
+pub mod tabs;
 
 pub use app::TuiApp;
-pub use colors::TableColors;
+pub use colors::{ColorMode, Highlight, SelectionStyle, TableColors};
+pub use columns::{Column, ColumnWidths, divergence_text};
+pub use favorites::{FavoritesSort, load_favorites, save_favorites};
+pub use grouping::{GroupBy, GroupSummary};
+pub use layouts::{SavedLayout, delete_layout, load_layouts, save_layout};
+pub use prompt::Prompt;
+pub use search::SearchScope;
+pub use selection::InitialSelection;
+pub use tabs::{TabConfig, TabView};
@@ -0,0 +1,101 @@
+/// Row below `selected` in a table of `count` rows, or `None` when the
+/// table is empty.
+pub fn next_row(selected: Option<usize>, count: usize, wrap: bool) -> Option<usize> {
+    if count == 0 {
+        return None;
+    }
+    Some(match selected {
+        Some(i) if i + 1 >= count && wrap => 0,
+        Some(i) if i + 1 >= count => count - 1,
+        Some(i) => i + 1,
+        None => 0,
+    })
+}
+
+/// Row above `selected` in a table of `count` rows, or `None` when the
+/// table is empty. Treats the top the way `next_row` treats the bottom:
+/// with `wrap` it jumps to the other end, otherwise it stays put.
+pub fn previous_row(selected: Option<usize>, count: usize, wrap: bool) -> Option<usize> {
+    if count == 0 {
+        return None;
+    }
+    Some(match selected {
+        Some(0) if wrap => count - 1,
+        Some(0) => 0,
+        Some(i) => (i - 1).min(count - 1),
+        None => 0,
+    })
+}
+
+/// Data rows among the `height` table rows starting at `offset`, where
+/// `display_rows[i]` is where data row `i` sits once repeated headers and
+/// group separators are inserted. At least 1, so paging always moves.
+pub fn data_rows_in_view(display_rows: &[usize], offset: usize, height: usize) -> usize {
+    display_rows
+        .iter()
+        .filter(|&&row| row >= offset && row < offset + height)
+        .count()
+        .max(1)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn separators_in_view_shrink_the_page() {
+        // Group separators at table rows 0 and 4
+        let display_rows = [1, 2, 3, 5, 6, 7, 8];
+        assert_eq!(data_rows_in_view(&display_rows, 0, 5), 3);
+        assert_eq!(data_rows_in_view(&display_rows, 4, 5), 4);
+    }
+
+    #[test]
+    fn plain_table_pages_by_its_height() {
+        let display_rows: Vec<usize> = (0..20).collect();
+        assert_eq!(data_rows_in_view(&display_rows, 5, 10), 10);
+        assert_eq!(data_rows_in_view(&display_rows, 15, 10), 5);
+        assert_eq!(data_rows_in_view(&[], 0, 10), 1);
+    }
+
+    #[test]
+    fn next_row_wraps_from_last_to_first() {
+        assert_eq!(next_row(Some(4), 5, true), Some(0));
+        assert_eq!(next_row(Some(0), 5, true), Some(1));
+    }
+
+    #[test]
+    fn next_row_stops_at_last_without_wrap() {
+        assert_eq!(next_row(Some(4), 5, false), Some(4));
+        assert_eq!(next_row(Some(0), 5, false), Some(1));
+    }
+
+    #[test]
+    fn previous_row_wraps_from_first_to_last() {
+        assert_eq!(previous_row(Some(0), 5, true), Some(4));
+        assert_eq!(previous_row(Some(4), 5, true), Some(3));
+    }
+
+    #[test]
+    fn previous_row_stops_at_first_without_wrap() {
+        assert_eq!(previous_row(Some(0), 5, false), Some(0));
+        assert_eq!(previous_row(Some(4), 5, false), Some(3));
+    }
+
+    #[test]
+    fn empty_table_has_no_row_in_either_mode() {
+        for wrap in [true, false] {
+            assert_eq!(next_row(Some(0), 0, wrap), None);
+            assert_eq!(next_row(None, 0, wrap), None);
+            assert_eq!(previous_row(Some(0), 0, wrap), None);
+            assert_eq!(previous_row(None, 0, wrap), None);
+        }
+    }
+
+    #[test]
+    fn selection_past_a_shrunk_table_is_pulled_back() {
+        assert_eq!(next_row(Some(9), 3, false), Some(2));
+        assert_eq!(next_row(Some(9), 3, true), Some(0));
+        assert_eq!(previous_row(Some(9), 3, false), Some(2));
+    }
+}
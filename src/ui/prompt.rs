@@ -0,0 +1,10 @@
+/// What the text input popup is collecting.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum Prompt {
+    #[default]
+    Search,
+    /// A funding threshold in the currently displayed unit.
+    Threshold,
+    /// Name to save the current view under as a layout.
+    LayoutName,
+}
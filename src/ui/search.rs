@@ -0,0 +1,86 @@
+use crate::config::SEARCH_SCOPES;
+use crate::data::{CoinData, search_key};
+
+/// Column the search popup matches against.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum SearchScope {
+    Coin,
+    Funding,
+    OpenInterest,
+}
+
+impl SearchScope {
+    pub fn label(&self) -> &'static str {
+        match self {
+            SearchScope::Coin => "Coin",
+            SearchScope::Funding => "Funding",
+            SearchScope::OpenInterest => "Open Interest",
+        }
+    }
+
+    /// Cycles to the next scope enabled in `SEARCH_SCOPES`.
+    pub fn next(&self) -> Self {
+        let position = SEARCH_SCOPES.iter().position(|s| s == self);
+        match position {
+            Some(i) => SEARCH_SCOPES[(i + 1) % SEARCH_SCOPES.len()],
+            None => SEARCH_SCOPES.first().copied().unwrap_or(SearchScope::Coin),
+        }
+    }
+
+    /// Coin searches match by name prefix, on either the canonical or the
+    /// venue-native symbol, ignoring case and separators like `/`; numeric
+    /// scopes parse the query as a number and match the first coin whose
+    /// value is at least that large.
+    pub fn matches(&self, coin: &CoinData, query: &str) -> bool {
+        match self {
+            SearchScope::Coin => {
+                let query = search_key(query);
+                std::iter::once(&coin.coin)
+                    .chain(coin.native_symbol.as_ref())
+                    .any(|symbol| search_key(symbol).starts_with(&query))
+            }
+            SearchScope::Funding => query
+                .trim()
+                .parse::<f64>()
+                .is_ok_and(|value| coin.funding >= value),
+            SearchScope::OpenInterest => query
+                .trim()
+                .parse::<f64>()
+                .is_ok_and(|value| coin.open_interest >= value),
+        }
+    }
+}
+
+impl Default for SearchScope {
+    fn default() -> Self {
+        SEARCH_SCOPES.first().copied().unwrap_or(SearchScope::Coin)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn coin(canonical: &str, native: &str) -> CoinData {
+        let mut coin = CoinData::new(canonical.to_string());
+        coin.native_symbol = Some(native.to_string());
+        coin
+    }
+
+    #[test]
+    fn coin_search_ignores_separators() {
+        let coin = coin("BTC", "BTC/USD");
+        for query in ["btc", "BTC/", "btc-usd", "BTC/USD", "btcusd"] {
+            assert!(SearchScope::Coin.matches(&coin, query), "{}", query);
+        }
+        assert!(!SearchScope::Coin.matches(&coin, "usd"));
+    }
+
+    #[test]
+    fn coin_search_handles_commas_and_leading_digits() {
+        assert!(SearchScope::Coin.matches(&coin("FOO,BAR", "FOO,BAR"), "foo,b"));
+        assert!(SearchScope::Coin.matches(&coin("1000PEPE", "1000PEPE"), "1000"));
+        assert!(SearchScope::Coin.matches(&coin("1000PEPE", "1000PEPE"), "1000pe"));
+        assert!(!SearchScope::Coin.matches(&coin("1000PEPE", "1000PEPE"), "pepe"));
+    }
+}
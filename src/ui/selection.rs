@@ -0,0 +1,11 @@
+/// Where the cursor starts when the app opens.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum InitialSelection {
+    /// The top row, whatever it is.
+    FirstRow,
+    /// The first favorite in the table, or the top row without favorites.
+    FirstFavorite,
+    /// The coin with the largest absolute funding, once the first batch of
+    /// updates has arrived.
+    HighestFunding,
+}
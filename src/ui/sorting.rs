@@ -0,0 +1,105 @@
+use std::cmp::Ordering;
+
+use crate::data::CoinData;
+
+/// Descending comparison for floats with NaN ordered last.
+pub fn cmp_desc(a: f64, b: f64) -> Ordering {
+    match (a.is_nan(), b.is_nan()) {
+        (true, true) => Ordering::Equal,
+        (true, false) => Ordering::Greater,
+        (false, true) => Ordering::Less,
+        (false, false) => b.total_cmp(&a),
+    }
+}
+
+/// Sorts rows by `value`, largest first with missing values last. Coin
+/// names are unique, so breaking ties on them gives a total order and equal
+/// values keep a fixed position between re-sorts.
+pub fn sort_desc_by(items: &mut [CoinData], value: impl Fn(&CoinData) -> Option<f64>) {
+    items.sort_unstable_by(|a, b| {
+        cmp_desc(value(a).unwrap_or(f64::NAN), value(b).unwrap_or(f64::NAN))
+            .then_with(|| a.coin.cmp(&b.coin))
+    });
+}
+
+/// Sorts rows alphabetically by coin name.
+pub fn sort_by_name(items: &mut [CoinData]) {
+    items.sort_unstable_by(|a, b| a.coin.cmp(&b.coin));
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::data::{CoinUpdate, HYPERLIQUID, LIGHTER};
+
+    fn coin(name: &str, funding: f64) -> CoinData {
+        let mut coin = CoinData::new(name.to_string());
+        coin.funding = funding;
+        coin
+    }
+
+    fn names(items: &[CoinData]) -> Vec<&str> {
+        items.iter().map(|c| c.coin.as_str()).collect()
+    }
+
+    #[test]
+    fn equal_values_are_ordered_by_name() {
+        let mut items = vec![
+            coin("SOL", 0.0),
+            coin("BTC", 0.0),
+            coin("ETH", 0.0),
+            coin("DOGE", 0.0),
+        ];
+        sort_desc_by(&mut items, |c| Some(c.funding));
+        assert_eq!(names(&items), ["BTC", "DOGE", "ETH", "SOL"]);
+    }
+
+    #[test]
+    fn re_sorting_any_permutation_gives_the_same_order() {
+        let mut first = vec![
+            coin("ETH", 0.0),
+            coin("BTC", 0.0002),
+            coin("SOL", 0.0),
+            coin("ARB", 0.0),
+        ];
+        let mut second = first.clone();
+        second.reverse();
+        sort_desc_by(&mut first, |c| Some(c.funding));
+        sort_desc_by(&mut second, |c| Some(c.funding));
+        assert_eq!(names(&first), ["BTC", "ARB", "ETH", "SOL"]);
+        assert_eq!(names(&first), names(&second));
+    }
+
+    #[test]
+    fn missing_and_nan_values_sort_last_by_name() {
+        let mut items = vec![
+            coin("ZEC", f64::NAN),
+            coin("BTC", 0.0001),
+            coin("ADA", f64::NAN),
+            coin("ETH", -0.0001),
+        ];
+        sort_desc_by(&mut items, |c| (!c.funding.is_nan()).then_some(c.funding));
+        assert_eq!(names(&items), ["BTC", "ETH", "ADA", "ZEC"]);
+    }
+
+    #[test]
+    fn venues_compare_on_hourly_funding_not_raw_units() {
+        // Hyperliquid reports a fraction, Lighter a percent: raw values
+        // would put the smaller Lighter rate first
+        let mut items = Vec::new();
+        for (symbol, funding, venue) in [("BTC", 0.0001, HYPERLIQUID), ("ETH", 0.005, LIGHTER)] {
+            let mut coin = CoinData::new(symbol.to_string());
+            coin.apply(&CoinUpdate::new(
+                symbol.to_string(),
+                funding,
+                1.0,
+                1.0,
+                3,
+                venue,
+            ));
+            items.push(coin);
+        }
+        sort_desc_by(&mut items, |c| Some(c.hourly_funding_value(false)));
+        assert_eq!(names(&items), ["BTC", "ETH"]);
+    }
+}
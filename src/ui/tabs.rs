@@ -0,0 +1,35 @@
+use ratatui::widgets::TableState;
+
+use crate::data::FundingRateRound;
+use crate::ui::Column;
+
+/// A named watchlist shown as a tab. An empty `coins` list shows every coin.
+#[derive(Clone, Copy, Debug)]
+pub struct TabConfig {
+    pub name: &'static str,
+    pub coins: &'static [&'static str],
+}
+
+impl TabConfig {
+    pub fn includes(&self, coin: &str) -> bool {
+        self.coins.is_empty() || self.coins.contains(&coin)
+    }
+}
+
+/// View state a tab keeps while another tab is active.
+#[derive(Clone, Debug)]
+pub struct TabView {
+    pub state: TableState,
+    pub round: FundingRateRound,
+    pub sorted_column: Option<Column>,
+}
+
+impl Default for TabView {
+    fn default() -> Self {
+        Self {
+            state: TableState::default().with_selected(0),
+            round: FundingRateRound::Hourly,
+            sorted_column: None,
+        }
+    }
+}
@@ -1,116 +1,204 @@
 use color_eyre::Result;
+use color_eyre::eyre::WrapErr;
 use futures::{SinkExt, StreamExt};
 use hyperliquid_rust_sdk::{BaseUrl, InfoClient, Message, Subscription};
 use serde_json::json;
 use std::collections::HashMap;
-use std::fs::OpenOptions;
-use std::io::Write;
+use std::collections::hash_map::Entry;
+use std::sync::Arc;
 use std::time::Duration;
 use tokio::sync::mpsc;
 use tokio::task::JoinHandle;
 use tokio::time::{interval, timeout};
 use tokio_tungstenite::{connect_async, tungstenite::Message as WsMessage};
 
+use crate::config::{
+    BACKGROUND_SUBSCRIBE_BATCH, INITIAL_SUBSCRIBE_LIMIT, LIGHTER_MARKET_REFRESH_SECS,
+    LIGHTER_WATCHLIST, SUBSCRIBE_ORDER, SUBSCRIBE_PRIORITY, SUBSCRIPTION_LOG_LEVEL,
+};
+use crate::data::{
+    CoinUpdate, HYPERLIQUID, LIGHTER, UpdateSender, canonical_symbol, is_valid_price,
+};
+use crate::exchange::exchanges_for;
+use crate::health::FeedHealth;
+use crate::paths::write_debug_log;
 use crate::request::coin_list_metadate_lighter;
 use crate::third_party::lighter::api_path::LIGHTER_STREAM_URL;
-use crate::third_party::lighter::data::MarketStatsMessage;
+use crate::third_party::lighter::data::{LighterFrame, MarketStatsMessage};
+use crate::websocket::{ConnectError, SocketConfig};
 
 fn log_debug(msg: String) {
-    if let Ok(mut file) = OpenOptions::new()
-        .create(true)
-        .append(true)
-        .open("/tmp/hype_debug.log")
-    {
-        let _ = writeln!(
-            file,
-            "[{}] {}",
-            chrono::Local::now().format("%H:%M:%S"),
-            msg
-        );
+    write_debug_log("", &msg);
+}
+
+/// How much subscription traffic goes to the debug log.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub enum SubscriptionLog {
+    /// Only subscriptions a venue rejected.
+    Failures,
+    /// Every subscription sent and whether it succeeded.
+    Sent,
+    /// Also the acks and control frames venues send back.
+    Frames,
+}
+
+/// Logs subscription traffic when `SUBSCRIPTION_LOG_LEVEL` is at least `level`.
+fn log_subscription(level: SubscriptionLog, msg: String) {
+    if SUBSCRIPTION_LOG_LEVEL >= level {
+        log_debug(format!("SUBSCRIPTION: {}", msg));
+    }
+}
+
+/// Order in which coins are subscribed when `INITIAL_SUBSCRIBE_LIMIT` is set.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum SubscribeOrder {
+    /// Keep the order returned by the venue's coin list.
+    Listed,
+    /// Subscribe coins in `SUBSCRIBE_PRIORITY` first, then the rest as listed.
+    Priority,
+}
+
+fn order_for_subscription(mut coins: Vec<String>) -> Vec<String> {
+    if SUBSCRIBE_ORDER == SubscribeOrder::Priority {
+        coins.sort_by_key(|coin| {
+            SUBSCRIBE_PRIORITY
+                .iter()
+                .position(|p| p == coin)
+                .unwrap_or(usize::MAX)
+        });
     }
+    coins
 }
 
 pub fn create_batch_websocket_task(
     coins: Vec<String>,
-    tx: mpsc::UnboundedSender<(String, f64, f64, f64, u8)>,
+    tx: UpdateSender,
     current_exchange: u8,
+    health: Arc<FeedHealth>,
 ) -> JoinHandle<Result<()>> {
     tokio::spawn(async move {
         log_debug(format!(
             "create_batch_websocket_task called with exchange: {}",
             current_exchange
         ));
-        match current_exchange {
-            1 => {
-                // Hyperliquid only
-                log_debug("Starting Hyperliquid websocket".to_string());
-                hyperliquid_websocket(coins, tx, 1).await
-            }
-            2 => {
-                // Lighter only
-                log_debug("Starting Lighter websocket".to_string());
-                lighter_websocket(coins, tx, 2).await
-            }
-            3 => {
-                // Both Hyperliquid and Lighter
-                log_debug("Starting BOTH Hyperliquid and Lighter websockets".to_string());
-                let tx_hl = tx.clone();
-                let tx_lt = tx.clone();
-                let coins_hl = coins.clone();
-                let coins_lt = coins.clone();
-
-                let hl_task =
-                    tokio::spawn(async move { hyperliquid_websocket(coins_hl, tx_hl, 3).await });
-                let lt_task =
-                    tokio::spawn(async move { lighter_websocket(coins_lt, tx_lt, 3).await });
-
-                // Wait for both to complete (or fail)
-                let _ = tokio::try_join!(hl_task, lt_task);
-                Ok(())
-            }
-            _ => {
-                // Default to Hyperliquid
-                log_debug(format!(
-                    "Unknown exchange {}, defaulting to Hyperliquid",
-                    current_exchange
-                ));
-                hyperliquid_websocket(coins, tx, 1).await
-            }
+        let exchanges = exchanges_for(current_exchange);
+        if let [exchange] = exchanges.as_slice() {
+            // Single venue: updates are tagged with the venue itself
+            log_debug(format!("Starting {} websocket", exchange.name()));
+            return exchange.run_feed(coins, tx, exchange.venue(), health).await;
         }
+
+        // Several venues side by side
+        log_debug(format!(
+            "Starting {} websockets",
+            exchanges
+                .iter()
+                .map(|e| e.name())
+                .collect::<Vec<_>>()
+                .join(" and ")
+        ));
+        let feeds = exchanges.iter().map(|exchange| {
+            tokio::spawn(exchange.run_feed(
+                coins.clone(),
+                tx.clone(),
+                current_exchange,
+                Arc::clone(&health),
+            ))
+        });
+
+        // Wait for all to complete (or fail)
+        futures::future::join_all(feeds).await;
+        Ok(())
     })
 }
 
-async fn hyperliquid_websocket(
+pub async fn hyperliquid_websocket(
     coins: Vec<String>,
-    tx: mpsc::UnboundedSender<(String, f64, f64, f64, u8)>,
+    tx: UpdateSender,
     exchange: u8,
+    health: Arc<FeedHealth>,
 ) -> Result<()> {
     log_debug(format!(
         "hyperliquid_websocket starting with {} coins, exchange={}",
         coins.len(),
         exchange
     ));
-    let mut client = InfoClient::new(None, Some(BaseUrl::Mainnet))
-        .await
-        .expect("Failed to create Hyperliquid client");
-
     let (sender_channel, mut receiver_channel) = mpsc::unbounded_channel::<Message>();
 
-    // Subscribe to all coins
-    for coin in coins.iter() {
-        let _ = client
-            .subscribe(
-                Subscription::ActiveAssetCtx { coin: coin.clone() },
-                sender_channel.clone(),
-            )
-            .await
-            .expect("Hyperliquid subscription failed");
-    }
+    let mut coins = order_for_subscription(coins);
+    let remaining = match INITIAL_SUBSCRIBE_LIMIT {
+        Some(limit) if limit < coins.len() => coins.split_off(limit),
+        _ => Vec::new(),
+    };
+
+    // Connect and subscribe to the initial window of coins, with backoff
+    let mut reconnect_delay = Duration::from_secs(1);
+    let max_reconnect_delay = Duration::from_secs(60);
+    let mut client = loop {
+        match connect_hyperliquid(&coins, &sender_channel, &health).await {
+            Ok(client) => break client,
+            Err(e) if !e.is_retryable() => return Err(e.into()),
+            Err(e) => {
+                log_debug(format!("{}, retrying in {:?}", e, reconnect_delay));
+                tokio::time::sleep(reconnect_delay).await;
+                reconnect_delay = std::cmp::min(reconnect_delay * 2, max_reconnect_delay);
+            }
+        }
+    };
+
+    // Subscribe to the remainder in the background so the first coins can
+    // render while the rest load. The task owns the client and keeps it alive
+    // until the message loop below goes away.
+    let background_sender = sender_channel.clone();
+    let background_health = Arc::clone(&health);
+    tokio::spawn(async move {
+        let total = remaining.len();
+        for (i, batch) in remaining
+            .chunks(BACKGROUND_SUBSCRIBE_BATCH.max(1))
+            .enumerate()
+        {
+            for coin in batch {
+                log_subscription(
+                    SubscriptionLog::Sent,
+                    format!("HL -> ActiveAssetCtx {{ coin: {} }}", coin),
+                );
+                match client
+                    .subscribe(
+                        Subscription::ActiveAssetCtx { coin: coin.clone() },
+                        background_sender.clone(),
+                    )
+                    .await
+                {
+                    Ok(id) => log_subscription(
+                        SubscriptionLog::Sent,
+                        format!("HL {} subscribed as #{}", coin, id),
+                    ),
+                    Err(e) => {
+                        log_debug(format!(
+                            "Background subscription for {} failed: {}",
+                            coin, e
+                        ));
+                        background_health.record_subscribe_failure(HYPERLIQUID);
+                    }
+                }
+            }
+            let done = (i * BACKGROUND_SUBSCRIBE_BATCH.max(1) + batch.len()).min(total);
+            log_debug(format!(
+                "Background subscriptions: {}/{} remaining coins subscribed",
+                done, total
+            ));
+            tokio::task::yield_now().await;
+        }
+        background_sender.closed().await;
+    });
+    drop(sender_channel);
+    health.set_connected(1, true);
 
     // Handle messages from all subscriptions
     while let Some(message) = receiver_channel.recv().await {
         match message {
             Message::ActiveAssetCtx(active_ctx) => {
+                health.record_update(1);
                 handle_hyperliquid_message(active_ctx, &tx, exchange);
             }
             _ => {
@@ -119,13 +207,150 @@ async fn hyperliquid_websocket(
         }
     }
 
+    health.set_connected(1, false);
+    Ok(())
+}
+
+/// Creates a Hyperliquid client and subscribes it to `coins`, bounding each
+/// step by the configured connect timeout so a black-holed network fails fast.
+async fn connect_hyperliquid(
+    coins: &[String],
+    sender: &mpsc::UnboundedSender<Message>,
+    health: &FeedHealth,
+) -> std::result::Result<InfoClient, ConnectError> {
+    let connect_timeout = SocketConfig::from_config().connect_timeout;
+    let mut client = timeout(
+        connect_timeout,
+        InfoClient::new(None, Some(BaseUrl::Mainnet)),
+    )
+    .await
+    .map_err(|_| ConnectError::Timeout("Hyperliquid connect".to_string(), connect_timeout))??;
+
+    // A coin the venue rejects is counted and skipped; a subscription that
+    // hangs means the connection itself is bad and fails the attempt
+    health.reset_subscribe_failures(HYPERLIQUID);
+    for coin in coins {
+        log_subscription(
+            SubscriptionLog::Sent,
+            format!("HL -> ActiveAssetCtx {{ coin: {} }}", coin),
+        );
+        let subscription = client.subscribe(
+            Subscription::ActiveAssetCtx { coin: coin.clone() },
+            sender.clone(),
+        );
+        let result = timeout(connect_timeout, subscription).await.map_err(|_| {
+            ConnectError::Timeout(
+                format!("Hyperliquid subscription for {}", coin),
+                connect_timeout,
+            )
+        })?;
+        match result {
+            Ok(id) => log_subscription(
+                SubscriptionLog::Sent,
+                format!("HL {} subscribed as #{}", coin, id),
+            ),
+            Err(e) => {
+                log_debug(format!("Subscription for {} failed: {}", coin, e));
+                health.record_subscribe_failure(HYPERLIQUID);
+            }
+        }
+    }
+
+    Ok(client)
+}
+
+/// Opens a Hyperliquid stream and subscribes it to all mids, failing if
+/// either step takes longer than `limit`. Used by the `--check` self-test.
+pub async fn probe_hyperliquid(limit: Duration) -> std::result::Result<(), ConnectError> {
+    let mut client = timeout(limit, InfoClient::new(None, Some(BaseUrl::Mainnet)))
+        .await
+        .map_err(|_| ConnectError::Timeout("Hyperliquid connect".to_string(), limit))??;
+    let (sender, _receiver) = mpsc::unbounded_channel::<Message>();
+    timeout(limit, client.subscribe(Subscription::AllMids, sender))
+        .await
+        .map_err(|_| ConnectError::Timeout("Hyperliquid subscription".to_string(), limit))??;
+    Ok(())
+}
+
+/// Opens a Lighter stream and closes it again, failing if the handshake
+/// takes longer than `limit`. Used by the `--check` self-test.
+pub async fn probe_lighter(limit: Duration) -> std::result::Result<(), ConnectError> {
+    let (mut ws_stream, _) = timeout(limit, connect_async(LIGHTER_STREAM_URL))
+        .await
+        .map_err(|_| ConnectError::Timeout("Lighter connect".to_string(), limit))??;
+    let _ = ws_stream.close(None).await;
     Ok(())
 }
 
-async fn lighter_websocket(
+/// Lighter channels to subscribe to: one per market in `LIGHTER_WATCHLIST`,
+/// or `market_stats/all` when the watchlist is empty, matches nothing, or
+/// `all` is forced.
+fn lighter_channels(market_map: &HashMap<u8, String>, all: bool) -> Vec<String> {
+    let mut market_ids: Vec<u8> = market_map
+        .iter()
+        .filter(|(_, symbol)| {
+            let symbol = canonical_symbol(symbol);
+            LIGHTER_WATCHLIST
+                .iter()
+                .any(|c| canonical_symbol(c) == symbol)
+        })
+        .map(|(id, _)| *id)
+        .collect();
+    if all || market_ids.is_empty() {
+        return vec!["market_stats/all".to_string()];
+    }
+
+    market_ids.sort_unstable();
+    market_ids
+        .iter()
+        .map(|id| format!("market_stats/{}", id))
+        .collect()
+}
+
+/// Re-fetches Lighter's market list every `every` and sends the mapping to
+/// the feed. Stops once the feed has gone away.
+async fn refresh_lighter_markets(
+    every: Duration,
+    map_tx: mpsc::UnboundedSender<HashMap<u8, String>>,
+) {
+    let mut ticker = interval(every);
+    ticker.tick().await; // The feed fetched the map just now
+    loop {
+        ticker.tick().await;
+        if map_tx.is_closed() {
+            return;
+        }
+        match coin_list_metadate_lighter().await {
+            Ok(funding_rates) => {
+                let map = funding_rates
+                    .into_iter()
+                    .map(|market| (market.market_id, market.symbol))
+                    .collect();
+                if map_tx.send(map).is_err() {
+                    return;
+                }
+            }
+            Err(e) => log_debug(format!("Lighter market map refresh failed: {}", e)),
+        }
+    }
+}
+
+/// Adds newly listed markets to the map, logging each discovery. Existing
+/// ids keep their symbols.
+fn merge_market_map(market_map: &mut HashMap<u8, String>, fresh: HashMap<u8, String>) {
+    for (id, symbol) in fresh {
+        if let Entry::Vacant(entry) = market_map.entry(id) {
+            log_debug(format!("Discovered Lighter market {} -> {}", id, symbol));
+            entry.insert(symbol);
+        }
+    }
+}
+
+pub async fn lighter_websocket(
     _coins: Vec<String>,
-    tx: mpsc::UnboundedSender<(String, f64, f64, f64, u8)>,
+    tx: UpdateSender,
     exchange: u8,
+    health: Arc<FeedHealth>,
 ) -> Result<()> {
     log_debug(format!("lighter_websocket starting, exchange={}", exchange));
 
@@ -133,7 +358,7 @@ async fn lighter_websocket(
     log_debug("Fetching Lighter market mapping...".to_string());
     let funding_rates = coin_list_metadate_lighter()
         .await
-        .map_err(|e| color_eyre::eyre::eyre!("Failed to fetch Lighter coin list: {}", e))?;
+        .wrap_err("Failed to fetch Lighter coin list")?;
 
     let mut market_map: HashMap<u8, String> = HashMap::new();
     for market in funding_rates {
@@ -144,10 +369,30 @@ async fn lighter_websocket(
         market_map.len()
     ));
 
+    // Refresh the map in the background so newly listed markets get their
+    // symbols without touching the stream
+    let (map_tx, mut map_rx) = mpsc::unbounded_channel::<HashMap<u8, String>>();
+    if LIGHTER_MARKET_REFRESH_SECS > 0 {
+        tokio::spawn(refresh_lighter_markets(
+            Duration::from_secs(LIGHTER_MARKET_REFRESH_SECS),
+            map_tx,
+        ));
+    }
+
+    let socket_config = SocketConfig::from_config();
+    if socket_config.ping_clamped {
+        log_debug(format!(
+            "Ping interval must be shorter than the read timeout, using {:?}",
+            socket_config.ping_interval
+        ));
+    }
+
     // Reconnection loop with exponential backoff
     let mut reconnect_delay = Duration::from_secs(1);
     let max_reconnect_delay = Duration::from_secs(60);
     let mut attempt = 0;
+    // Set once Lighter rejects a per-market channel, for the rest of the session
+    let mut subscribe_all = false;
 
     loop {
         attempt += 1;
@@ -159,16 +404,19 @@ async fn lighter_websocket(
             LIGHTER_STREAM_URL
         ));
 
-        let ws_result = connect_async(LIGHTER_STREAM_URL).await;
+        let connect_timeout = socket_config.connect_timeout;
+        let ws_result = timeout(connect_timeout, connect_async(LIGHTER_STREAM_URL)).await;
 
         let (ws_stream, _) = match ws_result {
-            Ok(stream) => {
+            Ok(Ok(stream)) => {
                 log_debug("Connected to Lighter WebSocket".to_string());
+                health.reset_subscribe_failures(LIGHTER);
+                health.set_connected(2, true);
                 // Reset reconnect delay on successful connection
                 reconnect_delay = Duration::from_secs(1);
                 stream
             }
-            Err(e) => {
+            Ok(Err(e)) => {
                 log_debug(format!(
                     "Lighter connection failed: {}, retrying in {:?}",
                     e, reconnect_delay
@@ -178,33 +426,48 @@ async fn lighter_websocket(
                 reconnect_delay = std::cmp::min(reconnect_delay * 2, max_reconnect_delay);
                 continue;
             }
+            Err(_) => {
+                log_debug(format!(
+                    "TIMEOUT: Lighter connect did not complete within {:?}, retrying in {:?}",
+                    connect_timeout, reconnect_delay
+                ));
+                tokio::time::sleep(reconnect_delay).await;
+                reconnect_delay = std::cmp::min(reconnect_delay * 2, max_reconnect_delay);
+                continue;
+            }
         };
 
         let (mut write, mut read) = ws_stream.split();
 
-        // Subscribe to market stats for all markets
-        let subscribe_msg = json!({
-            "type": "subscribe",
-            "channel": "market_stats/all"
-        });
+        // Subscribe to market stats for the watched markets, or all of them
+        let channels = lighter_channels(&market_map, subscribe_all);
+        let per_market = channels.len() > 1 || channels[0] != "market_stats/all";
+        let mut subscribed = true;
+        for channel in channels {
+            let subscribe_msg = json!({
+                "type": "subscribe",
+                "channel": channel
+            });
 
-        log_debug(format!(
-            "Sending subscription: {}",
-            subscribe_msg.to_string()
-        ));
-        if let Err(e) = write.send(WsMessage::Text(subscribe_msg.to_string())).await {
-            log_debug(format!(
-                "Failed to send subscription: {}, reconnecting...",
-                e
-            ));
+            log_subscription(SubscriptionLog::Sent, format!("LT -> {}", subscribe_msg));
+            if let Err(e) = write.send(WsMessage::Text(subscribe_msg.to_string())).await {
+                log_debug(format!(
+                    "Failed to send subscription: {}, reconnecting...",
+                    e
+                ));
+                subscribed = false;
+                break;
+            }
+        }
+        if !subscribed {
             tokio::time::sleep(reconnect_delay).await;
             reconnect_delay = std::cmp::min(reconnect_delay * 2, max_reconnect_delay);
             continue;
         }
         log_debug("Successfully sent subscription to Lighter WebSocket".to_string());
 
-        // Set up ping interval (30 seconds)
-        let mut ping_interval = interval(Duration::from_secs(30));
+        // Set up ping interval
+        let mut ping_interval = interval(socket_config.ping_interval);
         ping_interval.tick().await; // Skip the first immediate tick
 
         // Listen for messages
@@ -214,7 +477,7 @@ async fn lighter_websocket(
         loop {
             tokio::select! {
                 // Handle incoming messages with timeout
-                message = timeout(Duration::from_secs(60), read.next()) => {
+                message = timeout(socket_config.read_timeout, read.next()) => {
                     match message {
                         Ok(Some(Ok(WsMessage::Text(text)))) => {
                             log_debug(format!("Received text message: {} bytes", text.len()));
@@ -226,14 +489,47 @@ async fn lighter_websocket(
                             };
                             log_debug(format!("Raw message preview: {}", preview));
 
-                            if let Ok(parsed) = serde_json::from_str::<MarketStatsMessage>(&text) {
-                                log_debug(format!(
-                                    "Successfully parsed Lighter message with {} market stats",
-                                    parsed.market_stats.len()
-                                ));
-                                handle_lighter_message(parsed, &tx, exchange, &market_map);
-                            } else {
-                                log_debug(format!("Failed to parse message as MarketStatsMessage. First 300 chars: {}", &text[..text.len().min(300)]));
+                            match LighterFrame::parse(&text) {
+                                Ok(LighterFrame::Snapshot(parsed)) => {
+                                    log_debug(format!(
+                                        "Lighter snapshot on {} with {} market stats",
+                                        parsed.channel,
+                                        parsed.market_stats.len()
+                                    ));
+                                    health.record_update(2);
+                                    handle_lighter_message(parsed, &tx, exchange, &market_map);
+                                }
+                                Ok(LighterFrame::Update(parsed)) => {
+                                    log_debug(format!(
+                                        "Lighter update with {} market stats",
+                                        parsed.market_stats.len()
+                                    ));
+                                    health.record_update(2);
+                                    handle_lighter_message(parsed, &tx, exchange, &market_map);
+                                }
+                                Ok(LighterFrame::Subscribed(channel)) => {
+                                    log_debug(format!("Lighter confirmed subscription to {}", channel));
+                                    log_subscription(SubscriptionLog::Frames, format!("LT <- {}", text));
+                                }
+                                // Connection notices and channel errors
+                                Ok(LighterFrame::Control(value)) => {
+                                    log_subscription(SubscriptionLog::Frames, format!("LT <- {}", value));
+                                    if let Some(error) = value.get("error") {
+                                        log_debug(format!("Lighter returned an error: {}", error));
+                                        health.record_subscribe_failure(LIGHTER);
+                                        if per_market {
+                                            log_debug("Per-market subscription rejected, falling back to market_stats/all".to_string());
+                                            subscribe_all = true;
+                                            should_reconnect = true;
+                                            break;
+                                        }
+                                    } else {
+                                        log_debug(format!("Lighter control message: type={}", value["type"]));
+                                    }
+                                }
+                                Err(e) => {
+                                    log_debug(format!("Failed to parse Lighter frame: {}. First 300 chars: {}", e, &text[..text.len().min(300)]));
+                                }
                             }
                         }
                         Ok(Some(Ok(WsMessage::Binary(data)))) => {
@@ -266,7 +562,7 @@ async fn lighter_websocket(
                             break;
                         }
                         Err(_) => {
-                            log_debug("TIMEOUT: No message received within 60 seconds, reconnecting...".to_string());
+                            log_debug(format!("TIMEOUT: No message received within {:?}, reconnecting...", socket_config.read_timeout));
                             should_reconnect = true;
                             break;
                         }
@@ -275,6 +571,10 @@ async fn lighter_websocket(
                         }
                     }
                 }
+                // Merge refreshed market mappings
+                Some(fresh) = map_rx.recv() => {
+                    merge_market_map(&mut market_map, fresh);
+                }
                 // Send periodic pings
                 _ = ping_interval.tick() => {
                     log_debug("⏰ PING: Sending ping to keep connection alive".to_string());
@@ -289,6 +589,8 @@ async fn lighter_websocket(
             }
         }
 
+        health.set_connected(2, false);
+
         if should_reconnect {
             log_debug(format!("Reconnecting in {:?}...", reconnect_delay));
             tokio::time::sleep(reconnect_delay).await;
@@ -300,7 +602,7 @@ async fn lighter_websocket(
 
 fn handle_hyperliquid_message(
     active_ctx: hyperliquid_rust_sdk::ActiveAssetCtx,
-    tx: &mpsc::UnboundedSender<(String, f64, f64, f64, u8)>,
+    tx: &UpdateSender,
     exchange: u8,
 ) {
     if let hyperliquid_rust_sdk::AssetCtx::Perps(perps_ctx) = &active_ctx.data.ctx {
@@ -308,14 +610,22 @@ fn handle_hyperliquid_message(
         let funding = perps_ctx.funding.parse::<f64>().unwrap_or(0.0);
         let oi = perps_ctx.open_interest.parse::<f64>().unwrap_or(0.0);
         let price = perps_ctx.oracle_px.parse::<f64>().unwrap_or(0.0);
-        let _ = tx.send((coin.clone(), funding, oi, price, exchange));
+        let mark_price = perps_ctx.shared.mark_px.parse::<f64>().unwrap_or(0.0);
+        let prev_day_price = perps_ctx.shared.prev_day_px.parse::<f64>().unwrap_or(0.0);
+        let mut update = CoinUpdate::new(coin.clone(), funding, oi, price, exchange, HYPERLIQUID)
+            .with_mark_price(mark_price);
+        // Hyperliquid has no day range, only the price 24h ago
+        if is_valid_price(prev_day_price) && is_valid_price(mark_price) {
+            update = update.with_daily_change((mark_price / prev_day_price - 1.0) * 100.0);
+        }
+        let _ = tx.try_send(update);
         log_debug(format!("Sent HL data: {} exchange={}", coin, exchange));
     }
 }
 
 fn handle_lighter_message(
     parsed: MarketStatsMessage,
-    tx: &mpsc::UnboundedSender<(String, f64, f64, f64, u8)>,
+    tx: &UpdateSender,
     exchange: u8,
     market_map: &HashMap<u8, String>,
 ) {
@@ -326,9 +636,40 @@ fn handle_lighter_message(
             .cloned()
             .unwrap_or_else(|| format!("UNKNOWN_{}", stats.market_id));
         let funding = stats.current_funding_rate.parse::<f64>().unwrap_or(0.0);
+        let realized_funding = stats.funding_rate.parse::<f64>().ok();
         let price = stats.mark_price.parse::<f64>().unwrap_or(0.0);
-        let oi = (stats.open_interest.parse::<f64>().unwrap_or(0.0) / price) * 2.0f64;
-        let _ = tx.send((symbol.clone(), funding, oi, price, exchange));
+        let oi = stats.base_open_interest();
+        // The index price is Lighter's oracle; fall back to mark until it arrives
+        let index_price = stats.index_price.parse::<f64>().unwrap_or(0.0);
+        let oracle_price = if is_valid_price(index_price) {
+            index_price
+        } else {
+            price
+        };
+        // Lighter has no spot feed, and its index price is already the
+        // oracle, so no spot price is set and premium stays unknown rather
+        // than repeating the basis
+        let update = CoinUpdate::new(symbol.clone(), funding, oi, oracle_price, exchange, LIGHTER)
+            .with_mark_price(price)
+            .with_venue_timestamp(stats.funding_timestamp)
+            .with_daily_change(stats.daily_price_change);
+        let last_trade_price = stats.last_trade_price.parse::<f64>().unwrap_or(0.0);
+        let update = if is_valid_price(last_trade_price) {
+            update.with_last_trade_price(last_trade_price)
+        } else {
+            update
+        };
+        let update =
+            if is_valid_price(stats.daily_price_low) && is_valid_price(stats.daily_price_high) {
+                update.with_day_range(stats.daily_price_low, stats.daily_price_high)
+            } else {
+                update
+            };
+        let update = match realized_funding {
+            Some(realized) => update.with_realized_funding(realized),
+            None => update,
+        };
+        let _ = tx.try_send(update);
         log_debug(format!("Sent LT data: {} exchange={}", symbol, exchange));
     }
 }
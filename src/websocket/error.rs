@@ -0,0 +1,31 @@
+use std::time::Duration;
+
+use thiserror::Error;
+
+use crate::request::FetchError;
+
+/// Failures while establishing a venue stream.
+#[derive(Debug, Error)]
+pub enum ConnectError {
+    #[error("TIMEOUT: {0} did not complete within {1:?}")]
+    Timeout(String, Duration),
+    #[error("WebSocket error: {0}")]
+    WebSocket(#[from] tokio_tungstenite::tungstenite::Error),
+    #[error("Hyperliquid error: {0}")]
+    Hyperliquid(#[from] hyperliquid_rust_sdk::Error),
+    #[error(transparent)]
+    Fetch(#[from] FetchError),
+}
+
+impl ConnectError {
+    /// Whether another attempt may succeed. Connection level failures are
+    /// retryable; malformed metadata responses are not.
+    pub fn is_retryable(&self) -> bool {
+        match self {
+            ConnectError::Timeout(..)
+            | ConnectError::WebSocket(_)
+            | ConnectError::Hyperliquid(_) => true,
+            ConnectError::Fetch(e) => e.is_retryable(),
+        }
+    }
+}
@@ -1,3 +1,10 @@
 pub mod client;
+pub mod error;
+pub mod socket_config;
 
-pub use client::create_batch_websocket_task;
+pub use client::{
+    SubscribeOrder, SubscriptionLog, create_batch_websocket_task, hyperliquid_websocket,
+    lighter_websocket, probe_hyperliquid, probe_lighter,
+};
+pub use error::ConnectError;
+pub use socket_config::SocketConfig;
@@ -0,0 +1,82 @@
+use std::time::Duration;
+
+use crate::config::{CONNECT_TIMEOUT_SECS, WS_PING_INTERVAL_SECS, WS_READ_TIMEOUT_SECS};
+
+/// Connect and keepalive timings shared by every venue socket.
+#[derive(Clone, Copy, Debug)]
+pub struct SocketConfig {
+    /// Bound on a single connect or subscribe attempt.
+    pub connect_timeout: Duration,
+    /// How often to ping an otherwise quiet connection.
+    pub ping_interval: Duration,
+    /// How long to wait for any frame before treating the link as dead.
+    pub read_timeout: Duration,
+    /// Whether the configured ping interval had to be shortened to fit
+    /// under the read timeout.
+    pub ping_clamped: bool,
+}
+
+impl SocketConfig {
+    /// Builds the timings from config. A ping interval that isn't shorter
+    /// than the read timeout would let healthy links time out between pings,
+    /// so it is clamped to half the read timeout.
+    pub fn from_config() -> Self {
+        Self::from_secs(
+            CONNECT_TIMEOUT_SECS,
+            WS_PING_INTERVAL_SECS,
+            WS_READ_TIMEOUT_SECS,
+        )
+    }
+
+    /// Builds the timings from settings in seconds; zero intervals count as
+    /// one second.
+    fn from_secs(connect_timeout: u64, ping_interval: u64, read_timeout: u64) -> Self {
+        let read_timeout = Duration::from_secs(read_timeout.max(1));
+        let mut ping_interval = Duration::from_secs(ping_interval.max(1));
+        let ping_clamped = ping_interval >= read_timeout;
+        if ping_clamped {
+            ping_interval = read_timeout / 2;
+        }
+        Self {
+            connect_timeout: Duration::from_secs(connect_timeout),
+            ping_interval,
+            read_timeout,
+            ping_clamped,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn ping_inside_read_timeout_is_kept() {
+        let config = SocketConfig::from_secs(10, 30, 60);
+        assert_eq!(config.ping_interval, Duration::from_secs(30));
+        assert!(!config.ping_clamped);
+    }
+
+    #[test]
+    fn ping_at_or_past_read_timeout_is_halved_and_flagged() {
+        for ping in [60, 90] {
+            let config = SocketConfig::from_secs(10, ping, 60);
+            assert_eq!(config.ping_interval, Duration::from_secs(30));
+            assert!(config.ping_clamped);
+        }
+    }
+
+    #[test]
+    fn zero_settings_are_flagged_by_what_was_applied() {
+        // A zero ping becomes one second, which no longer fits under a one
+        // second timeout even though the raw settings looked ordered
+        let config = SocketConfig::from_secs(10, 0, 1);
+        assert_eq!(config.read_timeout, Duration::from_secs(1));
+        assert_eq!(config.ping_interval, Duration::from_millis(500));
+        assert!(config.ping_clamped);
+
+        let config = SocketConfig::from_secs(10, 0, 60);
+        assert_eq!(config.ping_interval, Duration::from_secs(1));
+        assert!(!config.ping_clamped);
+    }
+}